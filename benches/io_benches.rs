@@ -0,0 +1,52 @@
+use bencher::{benchmark_group, benchmark_main, Bencher};
+use std::io::{sink, BufWriter, Write};
+
+benchmark_group!(
+    benches,
+    bench_write_buffered_be,
+    bench_write_buffered_le,
+    bench_write_buffered_ne
+);
+benchmark_main!(benches);
+
+use simple_endian::{write_specific, BigEndian, LittleEndian};
+
+// Each value is written straight from a stack buffer, so going through a BufWriter shouldn't add a second copy.
+fn bench_write_buffered_be(b: &mut Bencher) {
+    let mut out = BufWriter::new(sink());
+    b.iter(|| {
+        for i in 0..1000u32 {
+            write_specific(&mut out, &BigEndian::from(i)).unwrap();
+            write_specific(&mut out, &BigEndian::from(i as u128)).unwrap();
+            write_specific(&mut out, &BigEndian::from(i as f64)).unwrap();
+            write_specific(&mut out, &BigEndian::from(i as u8)).unwrap();
+        }
+        out.flush().unwrap();
+    });
+}
+
+fn bench_write_buffered_le(b: &mut Bencher) {
+    let mut out = BufWriter::new(sink());
+    b.iter(|| {
+        for i in 0..1000u32 {
+            write_specific(&mut out, &LittleEndian::from(i)).unwrap();
+            write_specific(&mut out, &LittleEndian::from(i as u128)).unwrap();
+            write_specific(&mut out, &LittleEndian::from(i as f64)).unwrap();
+            write_specific(&mut out, &LittleEndian::from(i as u8)).unwrap();
+        }
+        out.flush().unwrap();
+    });
+}
+
+fn bench_write_buffered_ne(b: &mut Bencher) {
+    let mut out = BufWriter::new(sink());
+    b.iter(|| {
+        for i in 0..1000u32 {
+            out.write_all(&i.to_ne_bytes()).unwrap();
+            out.write_all(&(i as u128).to_ne_bytes()).unwrap();
+            out.write_all(&(i as f64).to_ne_bytes()).unwrap();
+            out.write_all(&(i as u8).to_ne_bytes()).unwrap();
+        }
+        out.flush().unwrap();
+    });
+}
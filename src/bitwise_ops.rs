@@ -10,8 +10,10 @@
 //! a ^= 0x5555555.into();
 //! ```
 
+#[allow(unused_imports)]
 use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
 
+#[allow(unused_imports)]
 use super::*;
 
 /// Implement the bitwise operations on the types.  These should be as fast in either endian, because they are endian-agnostic.
@@ -66,6 +68,7 @@ macro_rules! add_bitwise_ops {
 
 #[cfg(feature = "byte_impls")]
 mod bitwise_byte_ops {
+    #[allow(unused_imports)]
     use super::*;
     #[cfg(feature = "big_endian")]
     mod be {
@@ -85,6 +88,7 @@ mod bitwise_byte_ops {
 
 #[cfg(feature = "integer_impls")]
 mod bitwise_integer_ops {
+    #[allow(unused_imports)]
     use super::*;
     #[cfg(feature = "big_endian")]
     mod be {
@@ -0,0 +1,204 @@
+/*!
+A `tokio_util` codec that frames each message with a length header, so endian types can be sent over a `Framed` stream.
+Each frame is an `H` holding the payload length in bytes, followed by the payload `P`.
+
+```rust
+use bytes::BytesMut;
+use simple_endian::*;
+use tokio_util::codec::{Decoder, Encoder};
+
+let mut codec = EndianCodec::<u16be, u32le>::new();
+let mut buf = BytesMut::new();
+codec.encode(u32le::from(7), &mut buf).unwrap();
+assert_eq!(&buf[..], [0x00, 0x04, 0x07, 0x00, 0x00, 0x00]);
+assert_eq!(codec.decode(&mut buf).unwrap(), Some(u32le::from(7)));
+```
+*/
+use core::marker::PhantomData;
+use std::io::{self, Write};
+use std::vec::Vec;
+
+use bytes::{BufMut, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+#[allow(unused_imports)]
+use super::*;
+
+/// The largest payload accepted by default, the same as `tokio_util`'s `LengthDelimitedCodec`.
+const DEFAULT_MAX_LEN: usize = 8 * 1024 * 1024;
+
+/// Frames `P` payloads with an `H` length header.  Decoding waits until a whole frame has arrived, so a message split
+/// across several reads is returned once its last byte is in.  A frame whose payload doesn't use exactly the number of
+/// bytes given in its header is an `InvalidData` error.
+pub struct EndianCodec<H, P> {
+    max_len: usize,
+    _marker: PhantomData<fn() -> (H, P)>,
+}
+
+impl<H, P> EndianCodec<H, P> {
+    /// Creates a codec that accepts payloads of up to 8 MiB.
+    pub fn new() -> Self {
+        Self::with_max_len(DEFAULT_MAX_LEN)
+    }
+
+    /// Creates a codec that rejects payloads longer than `max_len` bytes, in either direction.
+    pub fn with_max_len(max_len: usize) -> Self {
+        Self {
+            max_len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The longest payload the codec accepts.
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+}
+
+impl<H, P> Default for EndianCodec<H, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H, P> Clone for EndianCodec<H, P> {
+    fn clone(&self) -> Self {
+        Self::with_max_len(self.max_len)
+    }
+}
+
+impl<H, P> core::fmt::Debug for EndianCodec<H, P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EndianCodec")
+            .field("max_len", &self.max_len)
+            .finish()
+    }
+}
+
+impl<H, P> Decoder for EndianCodec<H, P>
+where
+    H: LengthPrefix + EndianRead + WireSize,
+    P: EndianRead,
+{
+    type Item = P;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<P>> {
+        if src.len() < H::WIRE_SIZE {
+            src.reserve(H::WIRE_SIZE - src.len());
+            return Ok(None);
+        }
+        let header = H::read_from(&mut &src[..H::WIRE_SIZE])?;
+        let frame_len = header
+            .to_len()
+            .filter(|len| *len <= self.max_len)
+            .and_then(|len| H::WIRE_SIZE.checked_add(len))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "frame is too long"))?;
+        if src.len() < frame_len {
+            src.reserve(frame_len - src.len());
+            return Ok(None);
+        }
+
+        let frame = src.split_to(frame_len);
+        let mut payload = &frame[H::WIRE_SIZE..];
+        let value = P::read_from(&mut payload).map_err(|e| match e.kind() {
+            io::ErrorKind::UnexpectedEof => {
+                io::Error::new(io::ErrorKind::InvalidData, "payload overruns its frame")
+            }
+            _ => e,
+        })?;
+        if !payload.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "payload is shorter than its frame",
+            ));
+        }
+        Ok(Some(value))
+    }
+}
+
+impl<H, P> Encoder<P> for EndianCodec<H, P>
+where
+    H: LengthPrefix + EndianWrite + WireSize,
+    P: EndianWrite,
+{
+    type Error = io::Error;
+
+    fn encode(&mut self, item: P, dst: &mut BytesMut) -> io::Result<()> {
+        let mut payload = Vec::new();
+        item.write_to(&mut payload)?;
+        let header = Some(payload.len())
+            .filter(|len| *len <= self.max_len)
+            .and_then(H::from_len)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "frame is too long"))?;
+
+        dst.reserve(H::WIRE_SIZE + payload.len());
+        let mut writer = dst.writer();
+        header.write_to(&mut writer)?;
+        writer.write_all(&payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use bytes::BytesMut;
+    use std::io;
+    use tokio_util::codec::{Decoder, Encoder};
+
+    #[test]
+    fn decode_split_across_chunks() {
+        let mut codec = EndianCodec::<u32be, FixedBytes<8>>::new();
+        let first = FixedBytes(*b"message1");
+        let second = FixedBytes(*b"message2");
+        let mut wire = BytesMut::new();
+        codec.encode(first, &mut wire).unwrap();
+        codec.encode(second, &mut wire).unwrap();
+        assert_eq!(wire.len(), 2 * (4 + 8));
+
+        // Feed the stream three bytes at a time, so that both headers and payloads are split.
+        let mut src = BytesMut::new();
+        let mut decoded = Vec::new();
+        for chunk in wire.chunks(3) {
+            src.extend_from_slice(chunk);
+            while let Some(message) = codec.decode(&mut src).unwrap() {
+                decoded.push(message);
+            }
+        }
+        assert_eq!(decoded, [first, second]);
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn decode_wrong_length() {
+        let mut codec = EndianCodec::<BigEndian<u8>, u16le>::new();
+        let mut short = BytesMut::from(&[1u8, 0xff][..]);
+        let err = codec.decode(&mut short).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let mut long = BytesMut::from(&[3u8, 0x01, 0x02, 0x03][..]);
+        let err = codec.decode(&mut long).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn max_len() {
+        let mut codec = EndianCodec::<u16le, u64le>::with_max_len(4);
+        let mut buf = BytesMut::new();
+        let err = codec.encode(u64le::from(1), &mut buf).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        // The header is rejected before the payload has arrived.
+        let mut src = BytesMut::from(&[0x08u8, 0x00][..]);
+        let err = codec.decode(&mut src).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn frame_len_overflow() {
+        let mut codec = EndianCodec::<u64be, BigEndian<u8>>::with_max_len(usize::MAX);
+        let mut src = BytesMut::from(&[0xffu8; 8][..]);
+        let err = codec.decode(&mut src).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
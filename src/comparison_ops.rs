@@ -44,7 +44,11 @@ macro_rules! add_full_equality_ops {
                 self.to_native().cmp(&other.to_native())
             }
         }
-        add_equality_ops!($wrap_ty);
+        impl PartialOrd for $wrap_ty {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
     };
 }
 
@@ -139,4 +143,33 @@ mod tests {
         let be2 = BigEndian::from(6234.5678);
         assert!(be1 < be2);
     }
+
+    #[test]
+    fn sort_reverse_le() {
+        use core::cmp::Reverse;
+        // 0x100 is stored as [0x00, 0x01, ..], so sorting by stored bytes would put it below 0xff.
+        let mut records = Vec::from([
+            (LittleEndian::<u32>::from(0xff), "a"),
+            (LittleEndian::<u32>::from(0x100), "b"),
+            (LittleEndian::<u32>::from(1), "c"),
+            (LittleEndian::<u32>::from(0x1_0000), "d"),
+        ]);
+        records.sort_by_key(|(k, _)| Reverse(*k));
+        let order: Vec<_> = records.iter().map(|(_, v)| *v).collect();
+        assert_eq!(order, ["d", "b", "a", "c"]);
+        assert!(Reverse(BigEndian::<u32>::from(2)) < Reverse(BigEndian::<u32>::from(1)));
+    }
+
+    #[test]
+    fn binary_heap_le() {
+        use std::collections::BinaryHeap;
+        let mut heap = BinaryHeap::new();
+        for p in [0x100u32, 0xff, 3, 0x1_0000, 0x2000_0000].iter() {
+            heap.push(LittleEndian::from(*p));
+        }
+        let popped: Vec<u32> = core::iter::from_fn(|| heap.pop())
+            .map(|p| p.to_native())
+            .collect();
+        assert_eq!(popped, [0x2000_0000, 0x1_0000, 0x100, 0xff, 3]);
+    }
 }
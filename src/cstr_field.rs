@@ -0,0 +1,141 @@
+/*!
+`CStrField<N>`, a fixed-width text field holding a NUL-terminated ASCII or Latin-1 string.  The text ends at the first
+NUL, or fills the whole field if there isn't one, as with the OEM name in a FAT boot sector.
+
+```rust
+use simple_endian::*;
+
+let oem = CStrField::from_bytes(*b"MSDOS5.0");
+assert_eq!(oem.as_str(), Ok("MSDOS5.0"));
+
+let label = CStrField::<16>::new("disk").unwrap();
+assert_eq!(label.to_bytes()[..5], *b"disk\0");
+assert_eq!(label.as_str(), Ok("disk"));
+```
+*/
+
+/// `N` bytes of NUL-terminated (or full-width) ASCII or Latin-1 text.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[repr(transparent)]
+pub struct CStrField<const N: usize>([u8; N]);
+
+impl<const N: usize> CStrField<N> {
+    /// Stores `s`, padded with NULs.  Fails if `s` isn't ASCII, contains a NUL, or is longer than `N` bytes.
+    pub fn new(s: &str) -> Result<Self, &'static str> {
+        let b = s.as_bytes();
+        if !s.is_ascii() || b.contains(&0) {
+            return Err("CStrField text must be ASCII with no NUL");
+        }
+        if b.len() > N {
+            return Err("CStrField text is longer than the field");
+        }
+        let mut bytes = [0u8; N];
+        bytes[..b.len()].copy_from_slice(b);
+        Ok(Self(bytes))
+    }
+
+    /// Wraps the raw field.  Anything after the first NUL is kept, but isn't part of the text.
+    pub fn from_bytes(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the raw field, including the terminator and anything after it.
+    pub fn to_bytes(&self) -> [u8; N] {
+        self.0
+    }
+
+    /// The bytes of the text, up to but not including the first NUL.
+    pub fn as_bytes(&self) -> &[u8] {
+        let len = self.0.iter().position(|b| *b == 0).unwrap_or(N);
+        &self.0[..len]
+    }
+
+    /// The text as a `&str`.  Fails if it isn't ASCII; use `latin1_chars()` for text that may be Latin-1.
+    pub fn as_str(&self) -> Result<&str, &'static str> {
+        let b = self.as_bytes();
+        if !b.is_ascii() {
+            return Err("CStrField text is not ASCII");
+        }
+        core::str::from_utf8(b).map_err(|_| "CStrField text is not ASCII")
+    }
+
+    /// Decodes the text as Latin-1 (ISO 8859-1), which maps every byte to the `char` with the same value.
+    pub fn latin1_chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.as_bytes().iter().map(|b| char::from(*b))
+    }
+}
+
+impl<const N: usize> Default for CStrField<N> {
+    fn default() -> Self {
+        Self([0; N])
+    }
+}
+
+#[cfg(feature = "io-std")]
+mod io {
+    use super::*;
+    use crate::{EndianRead, EndianWrite, WireSize};
+    use std::io::{self, Read, Write};
+
+    impl<const N: usize> EndianRead for CStrField<N> {
+        fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+            let mut buf = [0u8; N];
+            reader.read_exact(&mut buf)?;
+            Ok(Self(buf))
+        }
+    }
+
+    impl<const N: usize> EndianWrite for CStrField<N> {
+        fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+            writer.write_all(&self.0)
+        }
+    }
+
+    impl<const N: usize> WireSize for CStrField<N> {
+        const WIRE_SIZE: usize = N;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn terminated() {
+        let field = CStrField::from_bytes(*b"NO NAME\0junk");
+        assert_eq!(field.as_bytes(), b"NO NAME");
+        assert_eq!(field.as_str(), Ok("NO NAME"));
+        assert_eq!(field.to_bytes(), *b"NO NAME\0junk");
+    }
+
+    #[test]
+    fn full_width() {
+        let field = CStrField::<8>::new("MSDOS5.0").unwrap();
+        assert_eq!(field.to_bytes(), *b"MSDOS5.0");
+        assert_eq!(field.as_str(), Ok("MSDOS5.0"));
+        assert!(CStrField::<7>::new("MSDOS5.0").is_err());
+        assert_eq!(CStrField::<4>::default().as_str(), Ok(""));
+    }
+
+    #[test]
+    fn latin1() {
+        let field = CStrField::from_bytes([b'c', b'a', b'f', 0xe9, 0, 0]);
+        assert!(field.as_str().is_err());
+        assert_eq!(field.latin1_chars().collect::<String>(), "café");
+        assert!(CStrField::<8>::new("café").is_err());
+        assert!(CStrField::<8>::new("a\0b").is_err());
+    }
+
+    #[cfg(feature = "io-std")]
+    #[test]
+    fn roundtrip_io() {
+        let field = CStrField::<11>::new("EFI").unwrap();
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &field).unwrap();
+        assert_eq!(buf, *b"EFI\0\0\0\0\0\0\0\0");
+        assert_eq!(
+            read_specific::<_, CStrField<11>>(&mut &buf[..]).unwrap(),
+            field
+        );
+    }
+}
@@ -0,0 +1,165 @@
+/*!
+`FixedBytes<N>`, an opaque block of `N` bytes for hashes, identifiers, reserved regions and the like.  Bytes have no
+byte order, so this is stored and written exactly as given.
+
+```rust
+use simple_endian::*;
+
+let hash = FixedBytes::from([0xde, 0xad, 0xbe, 0xef]);
+assert_eq!(hash.as_ref(), &[0xde, 0xad, 0xbe, 0xef]);
+assert_eq!(format!("{}", hash), "deadbeef");
+```
+*/
+use core::convert::TryFrom;
+
+/// `N` raw bytes with no byte order.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct FixedBytes<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> FixedBytes<N> {
+    /// Returns the bytes.
+    pub fn to_bytes(&self) -> [u8; N] {
+        self.0
+    }
+}
+
+impl<const N: usize> Default for FixedBytes<N> {
+    fn default() -> Self {
+        Self([0; N])
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for FixedBytes<N> {
+    fn from(v: [u8; N]) -> Self {
+        Self(v)
+    }
+}
+
+impl<const N: usize> From<FixedBytes<N>> for [u8; N] {
+    fn from(v: FixedBytes<N>) -> Self {
+        v.0
+    }
+}
+
+/// Fails unless the slice is exactly `N` bytes long.
+impl<const N: usize> TryFrom<&[u8]> for FixedBytes<N> {
+    type Error = core::array::TryFromSliceError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self(<[u8; N]>::try_from(v)?))
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for FixedBytes<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> AsMut<[u8]> for FixedBytes<N> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// Formats the bytes as hex, in storage order, with no separators.
+#[cfg(feature = "format")]
+mod format {
+    use super::*;
+    use core::fmt::{Display, Formatter, LowerHex, Result, UpperHex};
+
+    impl<const N: usize> LowerHex for FixedBytes<N> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            for b in self.0.iter() {
+                write!(f, "{:02x}", b)?;
+            }
+            Ok(())
+        }
+    }
+
+    impl<const N: usize> UpperHex for FixedBytes<N> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            for b in self.0.iter() {
+                write!(f, "{:02X}", b)?;
+            }
+            Ok(())
+        }
+    }
+
+    impl<const N: usize> Display for FixedBytes<N> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            LowerHex::fmt(self, f)
+        }
+    }
+}
+
+#[cfg(feature = "io-std")]
+mod io {
+    use super::*;
+    use crate::{EndianRead, EndianWrite, WireSize};
+    use std::io::{self, Read, Write};
+
+    impl<const N: usize> EndianRead for FixedBytes<N> {
+        fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+            let mut buf = [0u8; N];
+            reader.read_exact(&mut buf)?;
+            Ok(Self(buf))
+        }
+    }
+
+    impl<const N: usize> EndianWrite for FixedBytes<N> {
+        fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+            writer.write_all(&self.0)
+        }
+    }
+
+    impl<const N: usize> WireSize for FixedBytes<N> {
+        const WIRE_SIZE: usize = N;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use core::convert::TryFrom;
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn format_guid_hex() {
+        let guid = FixedBytes::<16>::from([
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+            0xee, 0xff,
+        ]);
+        assert_eq!(format!("{}", guid), "00112233445566778899aabbccddeeff");
+        assert_eq!(format!("{:X}", guid), "00112233445566778899AABBCCDDEEFF");
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let data = [1u8, 2, 3, 4, 5];
+        assert_eq!(
+            FixedBytes::<4>::try_from(&data[..4]).unwrap(),
+            FixedBytes([1, 2, 3, 4])
+        );
+        assert!(FixedBytes::<4>::try_from(&data[..]).is_err());
+    }
+
+    #[test]
+    fn default_is_zeroed() {
+        assert_eq!(FixedBytes::<64>::default().to_bytes(), [0u8; 64]);
+    }
+
+    #[cfg(feature = "io-std")]
+    #[test]
+    fn roundtrip_io() {
+        let bytes = FixedBytes::from(*b"reserved");
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &bytes).unwrap();
+        assert_eq!(buf, b"reserved");
+        assert_eq!(
+            read_specific::<_, FixedBytes<8>>(&mut &buf[..]).unwrap(),
+            bytes
+        );
+    }
+}
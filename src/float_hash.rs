@@ -0,0 +1,126 @@
+/*!
+Deterministic hashing of the float wrappers.  `f32` and `f64` aren't `Hash`, so `BigEndian<f32>` and friends aren't
+either.  Wrapping one in `FloatKey` hashes and compares its bit pattern instead, after mapping every NaN to a single
+canonical NaN and `-0.0` to `+0.0`.  This is deliberately not IEEE equality: a NaN key is equal to itself, and so can be
+found in a `HashMap`.
+
+```rust
+use simple_endian::*;
+use std::collections::HashSet;
+
+let mut seen = HashSet::new();
+seen.insert(FloatKey(f64be::from(0.0)));
+assert!(seen.contains(&FloatKey(f64be::from(-0.0))));
+
+seen.insert(FloatKey(f64be::from(f64::NAN)));
+assert!(seen.contains(&FloatKey(f64be::from(-f64::NAN))));
+```
+*/
+use core::hash::{Hash, Hasher};
+
+#[allow(unused_imports)]
+use super::*;
+
+/// Float wrappers that have a canonical bit pattern for hashing.
+pub trait CanonicalBits {
+    /// The unsigned integer the same width as the float.
+    type Bits: Copy + Eq + Hash;
+
+    /// The bits of the native value, with every NaN mapped to one NaN and `-0.0` mapped to `+0.0`.
+    fn canonical_bits(&self) -> Self::Bits;
+}
+
+/// Hashes and compares a float wrapper by its canonical bits.  This is a separate type because a `Hash` impl on
+/// `BigEndian<f32>` itself would conflict with the one derived for all `BigEndian<T: Hash>`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct FloatKey<E>(pub E);
+
+impl<E: CanonicalBits> Hash for FloatKey<E> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.canonical_bits().hash(state)
+    }
+}
+
+impl<E: CanonicalBits> PartialEq for FloatKey<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.canonical_bits() == other.0.canonical_bits()
+    }
+}
+
+impl<E: CanonicalBits> Eq for FloatKey<E> {}
+
+#[allow(unused_macros)]
+macro_rules! add_canonical_bits {
+    ($wrapper:ident, $float:ty, $bits:ty) => {
+        impl CanonicalBits for $wrapper<$float> {
+            type Bits = $bits;
+
+            fn canonical_bits(&self) -> $bits {
+                let v = self.to_native();
+                if v.is_nan() {
+                    <$float>::NAN.to_bits()
+                } else if v == 0.0 {
+                    0
+                } else {
+                    v.to_bits()
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "big_endian")]
+mod be {
+    use super::*;
+    add_canonical_bits!(BigEndian, f32, u32);
+    add_canonical_bits!(BigEndian, f64, u64);
+}
+
+#[cfg(feature = "little_endian")]
+mod le {
+    use super::*;
+    add_canonical_bits!(LittleEndian, f32, u32);
+    add_canonical_bits!(LittleEndian, f64, u64);
+}
+
+#[cfg(feature = "native_endian")]
+mod ne {
+    use super::*;
+    add_canonical_bits!(NativeEndian, f32, u32);
+    add_canonical_bits!(NativeEndian, f64, u64);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn signed_zeros_hash_equal() {
+        let pos = FloatKey(f32be::from(0.0));
+        let neg = FloatKey(f32be::from(-0.0));
+        assert_eq!(pos, neg);
+        assert_eq!(hash_of(&pos), hash_of(&neg));
+        assert_ne!(FloatKey(f32le::from(1.0)), FloatKey(f32le::from(-1.0)));
+    }
+
+    #[test]
+    fn nans_hash_equal() {
+        let quiet = FloatKey(f64le::from(f64::NAN));
+        let other = FloatKey(f64le::from(f64::from_bits(0xfff8_0000_dead_beef)));
+        assert!(other.0.to_native().is_nan());
+        assert_eq!(quiet, other);
+        assert_eq!(hash_of(&quiet), hash_of(&other));
+        assert_eq!(
+            FloatKey(f64be::from(f64::NAN)).0.canonical_bits(),
+            other.0.canonical_bits()
+        );
+    }
+}
@@ -0,0 +1,204 @@
+/*!
+Microsoft-style GUIDs.  These have a mixed-endian layout: the first three fields are little-endian integers, and the last
+eight bytes are stored as-is, so the stored bytes don't follow the order of the canonical text form.
+
+```rust
+use simple_endian::*;
+
+let esp: Guid = "C12A7328-F81F-11D2-BA4B-00A0C93EC93B".parse().unwrap();
+assert_eq!(esp.to_bytes()[..4], [0x28, 0x73, 0x2a, 0xc1]);
+assert_eq!(esp.to_string(), "c12a7328-f81f-11d2-ba4b-00a0c93ec93b");
+```
+*/
+use core::str::FromStr;
+
+use super::*;
+
+/// A GUID, laid out in memory exactly as Microsoft formats (GPT, COM, NTFS) store it.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct Guid {
+    pub data1: u32le,
+    pub data2: u16le,
+    pub data3: u16le,
+    pub data4: [u8; 8],
+}
+
+impl Guid {
+    /// Builds a GUID from its fields, as they appear in the text form.
+    pub fn new(data1: u32, data2: u16, data3: u16, data4: [u8; 8]) -> Self {
+        Self {
+            data1: data1.into(),
+            data2: data2.into(),
+            data3: data3.into(),
+            data4,
+        }
+    }
+
+    /// Builds a GUID from its 16 stored bytes.
+    pub fn from_bytes(b: [u8; 16]) -> Self {
+        Self::new(
+            u32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+            u16::from_le_bytes([b[4], b[5]]),
+            u16::from_le_bytes([b[6], b[7]]),
+            [b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15]],
+        )
+    }
+
+    /// Returns the 16 stored bytes.
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let mut b = [0u8; 16];
+        b[..4].copy_from_slice(&self.data1.to_native().to_le_bytes());
+        b[4..6].copy_from_slice(&self.data2.to_native().to_le_bytes());
+        b[6..8].copy_from_slice(&self.data3.to_native().to_le_bytes());
+        b[8..].copy_from_slice(&self.data4);
+        b
+    }
+}
+
+/// Parses the canonical `8-4-4-4-12` hex form, with or without surrounding braces, in either case.
+impl FromStr for Guid {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .unwrap_or(s);
+        let b = s.as_bytes();
+        if b.len() != 36 || b[8] != b'-' || b[13] != b'-' || b[18] != b'-' || b[23] != b'-' {
+            return Err("GUID must be in 8-4-4-4-12 form");
+        }
+        let hex = |range: core::ops::Range<usize>| {
+            s.get(range)
+                .filter(|part| part.bytes().all(|c| c.is_ascii_hexdigit()))
+                .and_then(|part| u64::from_str_radix(part, 16).ok())
+                .ok_or("GUID contains a non-hex digit")
+        };
+        let tail = hex(24..36)?.to_be_bytes();
+        let clock_seq = hex(19..23)? as u16;
+        Ok(Self::new(
+            hex(0..8)? as u32,
+            hex(9..13)? as u16,
+            hex(14..18)? as u16,
+            [
+                (clock_seq >> 8) as u8,
+                clock_seq as u8,
+                tail[2],
+                tail[3],
+                tail[4],
+                tail[5],
+                tail[6],
+                tail[7],
+            ],
+        ))
+    }
+}
+
+/// Formats the GUID in the canonical lowercase `8-4-4-4-12` form.
+#[cfg(feature = "format")]
+impl core::fmt::Display for Guid {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let d = &self.data4;
+        write!(
+            f,
+            "{:08x}-{:04x}-{:04x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+            self.data1.to_native(),
+            self.data2.to_native(),
+            self.data3.to_native(),
+            d[0],
+            d[1],
+            d[2],
+            d[3],
+            d[4],
+            d[5],
+            d[6],
+            d[7]
+        )
+    }
+}
+
+#[cfg(feature = "io-std")]
+mod io {
+    use super::*;
+    use std::io::{self, Read, Write};
+
+    impl EndianRead for Guid {
+        fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+            let mut buf = [0u8; 16];
+            reader.read_exact(&mut buf)?;
+            Ok(Self::from_bytes(buf))
+        }
+    }
+
+    impl EndianWrite for Guid {
+        fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+            writer.write_all(&self.to_bytes())
+        }
+    }
+
+    impl WireSize for Guid {
+        const WIRE_SIZE: usize = 16;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use core::mem::size_of;
+
+    // The EFI System Partition type GUID, as it appears in a GPT partition entry.
+    const ESP_BYTES: [u8; 16] = [
+        0x28, 0x73, 0x2a, 0xc1, 0x1f, 0xf8, 0xd2, 0x11, 0xba, 0x4b, 0x00, 0xa0, 0xc9, 0x3e, 0xc9,
+        0x3b,
+    ];
+
+    #[test]
+    fn same_size() {
+        assert_eq!(size_of::<Guid>(), 16);
+    }
+
+    #[test]
+    fn known_bytes() {
+        let guid = Guid::from_bytes(ESP_BYTES);
+        assert_eq!(guid.data1.to_native(), 0xc12a7328);
+        assert_eq!(guid.data2.to_native(), 0xf81f);
+        assert_eq!(guid.data3.to_native(), 0x11d2);
+        assert_eq!(guid.data4, [0xba, 0x4b, 0x00, 0xa0, 0xc9, 0x3e, 0xc9, 0x3b]);
+        assert_eq!(guid.to_bytes(), ESP_BYTES);
+    }
+
+    #[test]
+    fn parse_and_format() {
+        let guid: Guid = "{C12A7328-F81F-11D2-BA4B-00A0C93EC93B}".parse().unwrap();
+        assert_eq!(guid.to_bytes(), ESP_BYTES);
+        assert_eq!(guid.to_string(), "c12a7328-f81f-11d2-ba4b-00a0c93ec93b");
+        assert_eq!(guid.to_string().parse::<Guid>().unwrap(), guid);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!("c12a7328-f81f-11d2-ba4b-00a0c93ec93"
+            .parse::<Guid>()
+            .is_err());
+        assert!("c12a7328f81f-11d2-ba4b-00a0c93ec93b0"
+            .parse::<Guid>()
+            .is_err());
+        assert!("c12a7328-f81f-11d2-ba4b-00a0c93ec9xb"
+            .parse::<Guid>()
+            .is_err());
+        assert!("+12a7328-f81f-11d2-ba4b-00a0c93ec93b"
+            .parse::<Guid>()
+            .is_err());
+    }
+
+    #[cfg(feature = "io-std")]
+    #[test]
+    fn roundtrip_io() {
+        let guid = Guid::from_bytes(ESP_BYTES);
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &guid).unwrap();
+        assert_eq!(buf, ESP_BYTES);
+        assert_eq!(read_specific::<_, Guid>(&mut &buf[..]).unwrap(), guid);
+    }
+}
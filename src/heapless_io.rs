@@ -0,0 +1,200 @@
+/*!
+Reading length-prefixed data into fixed-capacity `heapless` containers, so that a variable-length field never needs an
+allocation.  The `split_*` functions parse from a byte slice and work without `std`; with `io-std`, the `read_*`
+functions do the same from a reader.  Either way, a length larger than the container's capacity is an error, reported
+before any of the data is parsed.
+
+```rust
+use simple_endian::*;
+
+let data = [0x00, 0x08, b's', b'e', b'n', b's', b'o', b'r', b'-', b'7', 0xff];
+let (name, rest) = split_prefixed_string::<u16be, 16>(&data).unwrap();
+assert_eq!(name, "sensor-7");
+assert_eq!(rest, [0xff]);
+```
+*/
+use heapless::{String, Vec};
+
+#[allow(unused_imports)]
+use super::*;
+
+/// Parses a count of type `C`, followed by that many `E` values, into a `heapless::Vec` holding at most `N`.  Returns
+/// the values along with the rest of `data`.
+pub fn split_counted_heapless<C, E, const N: usize>(
+    data: &[u8],
+) -> Result<(Vec<E, N>, &[u8]), &'static str>
+where
+    C: LengthPrefix + SplitPrefix,
+    E: SplitPrefix,
+{
+    let (count, mut rest) = C::split_prefix(data)?;
+    let count = count
+        .to_len()
+        .filter(|count| *count <= N)
+        .ok_or("length exceeds the container's capacity")?;
+    let mut values = Vec::new();
+    for _ in 0..count {
+        let (value, tail) = E::split_prefix(rest)?;
+        // Can't fail, as the count was checked against N.
+        let _ = values.push(value);
+        rest = tail;
+    }
+    Ok((values, rest))
+}
+
+/// Parses a byte length of type `C`, followed by that many bytes of UTF-8, into a `heapless::String` of at most `N`
+/// bytes.  Returns the text along with the rest of `data`.
+pub fn split_prefixed_string<C, const N: usize>(
+    data: &[u8],
+) -> Result<(String<N>, &[u8]), &'static str>
+where
+    C: LengthPrefix + SplitPrefix,
+{
+    let (len, rest) = C::split_prefix(data)?;
+    let len = len
+        .to_len()
+        .filter(|len| *len <= N)
+        .ok_or("length exceeds the container's capacity")?;
+    if rest.len() < len {
+        return Err("not enough data for the value");
+    }
+    let (text, rest) = rest.split_at(len);
+    let bytes = Vec::from_slice(text).map_err(|_| "length exceeds the container's capacity")?;
+    let text = String::from_utf8(bytes).map_err(|_| "text is not valid UTF-8")?;
+    Ok((text, rest))
+}
+
+/// Reading into `heapless` containers from `std::io`.
+#[cfg(feature = "io-std")]
+mod std_io {
+    use std::io::{self, Read, Write};
+
+    use super::*;
+
+    /// The error for a length that doesn't fit in the container.
+    fn too_long() -> io::Error {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "length exceeds the container's capacity",
+        )
+    }
+
+    /// Reads a count of type `C`, followed by that many `E` values, into a `heapless::Vec` holding at most `N`.
+    pub fn read_counted_heapless<R, C, E, const N: usize>(reader: &mut R) -> io::Result<Vec<E, N>>
+    where
+        R: Read + ?Sized,
+        C: LengthPrefix + EndianRead,
+        E: EndianRead,
+    {
+        let count = C::read_from(reader)?
+            .to_len()
+            .filter(|count| *count <= N)
+            .ok_or_else(too_long)?;
+        let mut values = Vec::new();
+        for _ in 0..count {
+            // Can't fail, as the count was checked against N.
+            let _ = values.push(E::read_from(reader)?);
+        }
+        Ok(values)
+    }
+
+    /// Reads a byte length of type `C`, followed by that many bytes of UTF-8, into a `heapless::String` of at most `N`
+    /// bytes.  Text that isn't valid UTF-8 is an `InvalidData` error.
+    pub fn read_prefixed_string<R, C, const N: usize>(reader: &mut R) -> io::Result<String<N>>
+    where
+        R: Read + ?Sized,
+        C: LengthPrefix + EndianRead,
+    {
+        let len = C::read_from(reader)?
+            .to_len()
+            .filter(|len| *len <= N)
+            .ok_or_else(too_long)?;
+        let mut bytes = Vec::<u8, N>::new();
+        bytes.resize_default(len).map_err(|_| too_long())?;
+        reader.read_exact(&mut bytes)?;
+        String::from_utf8(bytes)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "text is not valid UTF-8"))
+    }
+
+    /// Writes the byte length of `s` as a `C`, followed by its UTF-8.  Fails with `InvalidInput` if the length doesn't fit
+    /// in a `C`.  `write_counted` does the same for a `heapless::Vec`, through its slice.
+    pub fn write_prefixed_str<W, C>(writer: &mut W, s: &str) -> io::Result<()>
+    where
+        W: Write + ?Sized,
+        C: LengthPrefix + EndianWrite,
+    {
+        let len = C::from_len(s.len()).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "text is too long for the length type",
+            )
+        })?;
+        len.write_to(writer)?;
+        writer.write_all(s.as_bytes())
+    }
+}
+#[cfg(feature = "io-std")]
+pub use std_io::*;
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn split_heapless() {
+        let data = [3u8, 1, 0, 2, 0, 3, 0, 0x00, 0x03, b'a', 0xc3, 0xa9, 0xaa];
+        let (values, rest) = split_counted_heapless::<BigEndian<u8>, u16le, 4>(&data).unwrap();
+        assert_eq!(values, [1.into(), 2.into(), 3.into()]);
+        let (text, rest) = split_prefixed_string::<u16be, 8>(rest).unwrap();
+        assert_eq!(text, "aé");
+        assert_eq!(rest, [0xaa]);
+    }
+
+    #[test]
+    fn split_overflow_capacity() {
+        let data = [3u8, 1, 0, 2, 0, 3, 0];
+        assert!(split_counted_heapless::<BigEndian<u8>, u16le, 2>(&data).is_err());
+        assert!(split_counted_heapless::<BigEndian<u8>, u16le, 4>(&data[..5]).is_err());
+
+        let text = [0u8, 5, b'h', b'e', b'l', b'l', b'o'];
+        assert!(split_prefixed_string::<u16be, 4>(&text).is_err());
+        assert!(split_prefixed_string::<u16be, 8>(&text[..6]).is_err());
+        assert!(split_prefixed_string::<u16be, 4>(&[0, 2, 0xc3, 0x28]).is_err());
+    }
+
+    #[cfg(feature = "io-std")]
+    #[test]
+    fn roundtrip_heapless() {
+        let mut buf = Vec::new();
+        write_counted::<_, BigEndian<u8>, u16le>(&mut buf, &[1.into(), 2.into(), 3.into()])
+            .unwrap();
+        write_prefixed_str::<_, u16be>(&mut buf, "héllo").unwrap();
+
+        let mut reader = &buf[..];
+        let values: heapless::Vec<u16le, 4> =
+            read_counted_heapless::<_, BigEndian<u8>, _, 4>(&mut reader).unwrap();
+        assert_eq!(values, [1.into(), 2.into(), 3.into()]);
+        let text: heapless::String<8> = read_prefixed_string::<_, u16be, 8>(&mut reader).unwrap();
+        assert_eq!(text, "héllo");
+        assert!(reader.is_empty());
+    }
+
+    #[cfg(feature = "io-std")]
+    #[test]
+    fn overflow_capacity() {
+        let mut buf = Vec::new();
+        write_counted::<_, BigEndian<u8>, u16le>(&mut buf, &[1.into(), 2.into(), 3.into()])
+            .unwrap();
+        let err = read_counted_heapless::<_, BigEndian<u8>, u16le, 2>(&mut &buf[..]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+        let mut buf = Vec::new();
+        write_prefixed_str::<_, u16be>(&mut buf, "too long for four").unwrap();
+        let err = read_prefixed_string::<_, u16be, 4>(&mut &buf[..]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+        let invalid = [0u8, 2, 0xc3, 0x28];
+        let err = read_prefixed_string::<_, u16be, 4>(&mut &invalid[..]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
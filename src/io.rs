@@ -0,0 +1,1545 @@
+/*!
+Reading and writing endian types through `std::io`.  The byte order is part of the type, so `read_specific` and
+`write_specific` never need to be told which order to use, and always produce the same bytes regardless of host endian.
+
+```rust
+use simple_endian::*;
+
+let mut buf = Vec::new();
+write_specific(&mut buf, &u32be::from(0x0a0b0c0d)).unwrap();
+assert_eq!(buf, [0x0a, 0x0b, 0x0c, 0x0d]);
+
+let value: u32be = read_specific(&mut &buf[..]).unwrap();
+assert_eq!(value.to_native(), 0x0a0b0c0d);
+```
+*/
+use core::cmp::Ordering;
+use core::hash::Hasher;
+use core::num::Saturating;
+use core::ops::{Range, RangeInclusive};
+use std::boxed::Box;
+use std::collections::hash_map::DefaultHasher;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::rc::Rc;
+use std::string::String;
+use std::sync::Arc;
+use std::vec::Vec;
+
+#[allow(unused_imports)]
+use super::*;
+
+/// A type that can be read from a byte stream in a fixed byte order.
+pub trait EndianRead: Sized {
+    fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self>;
+}
+
+/// A type that can be written to a byte stream in a fixed byte order.
+pub trait EndianWrite {
+    fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// A reference writes the value it points to, so references can be passed through generic code.
+impl<T: EndianWrite + ?Sized> EndianWrite for &T {
+    fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        (**self).write_to(writer)
+    }
+}
+
+/// A box reads its contents and moves them onto the heap.
+impl<T: EndianRead> EndianRead for Box<T> {
+    fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+        Ok(Box::new(T::read_from(reader)?))
+    }
+}
+
+impl<T: EndianWrite + ?Sized> EndianWrite for Box<T> {
+    fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        (**self).write_to(writer)
+    }
+}
+
+impl<T: EndianWrite + ?Sized> EndianWrite for Rc<T> {
+    fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        (**self).write_to(writer)
+    }
+}
+
+impl<T: EndianWrite + ?Sized> EndianWrite for Arc<T> {
+    fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        (**self).write_to(writer)
+    }
+}
+
+/// A slice writes each element in order, with no length.  Use `write_counted` to write the length first.
+impl<E: EndianWrite> EndianWrite for [E] {
+    fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        for value in self {
+            value.write_to(writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// An array reads and writes each element in order.  The elements don't need to be `Copy` or `Default`.
+impl<E: EndianRead, const N: usize> EndianRead for [E; N] {
+    fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+        let mut values = Vec::with_capacity(N);
+        for _ in 0..N {
+            values.push(E::read_from(reader)?);
+        }
+        match values.try_into() {
+            Ok(array) => Ok(array),
+            Err(_) => unreachable!("exactly N values were read"),
+        }
+    }
+}
+
+impl<E: EndianWrite, const N: usize> EndianWrite for [E; N] {
+    fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        self[..].write_to(writer)
+    }
+}
+
+/// A type that always takes up the same number of bytes when written, which makes it possible to seek straight to the
+/// `n`th record of a table.
+pub trait WireSize {
+    /// The number of bytes the type reads and writes.  For a concrete type this is usable in const contexts, such as
+    /// `[0u8; <(u32be, u16le)>::WIRE_SIZE]` for a buffer of exactly the right size.
+    const WIRE_SIZE: usize;
+}
+
+impl<E: WireSize, const N: usize> WireSize for [E; N] {
+    const WIRE_SIZE: usize = E::WIRE_SIZE * N;
+}
+
+/// Reads a value of type `E` from `reader`.
+pub fn read_specific<R: Read + ?Sized, E: EndianRead>(reader: &mut R) -> io::Result<E> {
+    E::read_from(reader)
+}
+
+/// Reads all `E::WIRE_SIZE` bytes of an `E` into a buffer before decoding any of it.  This takes one read of the inner
+/// reader for most values, rather than one per field, and a truncated value fails with an `UnexpectedEof` that says how
+/// many bytes were needed and how many there were.
+pub fn read_specific_whole<R: Read + ?Sized, E: EndianRead + WireSize>(
+    reader: &mut R,
+) -> io::Result<E> {
+    let mut buf = std::vec![0u8; E::WIRE_SIZE];
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    std::format!("needed {} bytes, got {}", E::WIRE_SIZE, filled),
+                ))
+            }
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    E::read_from(&mut &buf[..])
+}
+
+/// Writes `value` to `writer`.
+pub fn write_specific<W: Write + ?Sized, E: EndianWrite + ?Sized>(
+    writer: &mut W,
+    value: &E,
+) -> io::Result<()> {
+    value.write_to(writer)
+}
+
+/// Parses an `E` from the start of `data`, returning it along with the rest of `data`, so that consecutive headers can
+/// be parsed from a slice without a `Cursor`.  This works for any `EndianRead` type, which needs `std::io`; without
+/// `std`, the primitive wrappers can be parsed the same way with `SplitPrefix::split_prefix`.
+pub fn parse_prefix<E: EndianRead + WireSize>(data: &[u8]) -> Result<(E, &[u8]), &'static str> {
+    if data.len() < E::WIRE_SIZE {
+        return Err("not enough data for the value");
+    }
+    let (mut value, rest) = data.split_at(E::WIRE_SIZE);
+    let value = E::read_from(&mut value).map_err(|_| "data is not a valid value")?;
+    Ok((value, rest))
+}
+
+/// Writes `value` as lowercase hexadecimal text, two digits per byte, for logs, test fixtures, and text protocols.
+pub fn to_hex<E: EndianWrite + ?Sized>(value: &E) -> io::Result<String> {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut bytes = Vec::new();
+    value.write_to(&mut bytes)?;
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        hex.push(char::from(DIGITS[usize::from(b >> 4)]));
+        hex.push(char::from(DIGITS[usize::from(b & 0xf)]));
+    }
+    Ok(hex)
+}
+
+/// Reads an `E` from hexadecimal text in either case, as written by `to_hex`.  Fails if the text isn't an even number of
+/// hex digits, or if it holds more or less than exactly one valid `E`.
+pub fn from_hex<E: EndianRead>(hex: &str) -> Result<E, &'static str> {
+    fn digit(c: u8) -> Result<u8, &'static str> {
+        match c {
+            b'0'..=b'9' => Ok(c - b'0'),
+            b'a'..=b'f' => Ok(c - b'a' + 10),
+            b'A'..=b'F' => Ok(c - b'A' + 10),
+            _ => Err("hex text contains a non-hex character"),
+        }
+    }
+
+    let hex = hex.as_bytes();
+    if !hex.len().is_multiple_of(2) {
+        return Err("hex text has an odd number of digits");
+    }
+    let bytes = hex
+        .chunks(2)
+        .map(|pair| Ok(digit(pair[0])? << 4 | digit(pair[1])?))
+        .collect::<Result<Vec<u8>, &'static str>>()?;
+    let mut data = &bytes[..];
+    let value = E::read_from(&mut data).map_err(|_| "hex text is not a valid value")?;
+    if !data.is_empty() {
+        return Err("hex text is longer than the value");
+    }
+    Ok(value)
+}
+
+/// Writes `value` followed by enough `pad` bytes to make `total_size` bytes in all, e.g. to fill out a fixed-size
+/// sector.  Fails with `InvalidInput`, without writing anything, if `value` is larger than `total_size`.
+pub fn write_specific_padded<W: Write + ?Sized, E: EndianWrite + ?Sized>(
+    writer: &mut W,
+    value: &E,
+    total_size: usize,
+    pad: u8,
+) -> io::Result<()> {
+    let mut buf = Vec::with_capacity(total_size);
+    value.write_to(&mut buf)?;
+    if buf.len() > total_size {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "value is larger than the padded size",
+        ));
+    }
+    buf.resize(total_size, pad);
+    writer.write_all(&buf)
+}
+
+/// Reads a `Src` from `reader` and writes it to `writer` as a `Dst`, such as a header read in big endian and written
+/// back out in little endian.
+pub fn transcode<R, W, Src, Dst>(reader: &mut R, writer: &mut W) -> io::Result<()>
+where
+    R: Read + ?Sized,
+    W: Write + ?Sized,
+    Src: EndianRead,
+    Dst: EndianWrite + From<Src>,
+{
+    Dst::from(Src::read_from(reader)?).write_to(writer)
+}
+
+/// The error for a value that consumed no input, which would make a read-until-end loop spin forever.
+fn empty_value() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "value reads no bytes, so the stream would never end",
+    )
+}
+
+/// Reads `E` values from `reader` until it reaches the end of the stream.  The stream has to end cleanly on a value
+/// boundary: if the final value is cut short, this returns an `UnexpectedEof` error rather than dropping it.  A value
+/// that reads no bytes, such as `FixedBytes<0>`, would never reach the end, so it is an `InvalidInput` error.
+pub fn read_all<R: Read + ?Sized, E: EndianRead>(reader: &mut R) -> io::Result<Vec<E>> {
+    let mut values = Vec::new();
+    loop {
+        let mut counted = CountingReader {
+            inner: &mut *reader,
+            count: 0,
+        };
+        match E::read_from(&mut counted) {
+            Ok(_) if counted.count == 0 => return Err(empty_value()),
+            Ok(value) => values.push(value),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof && counted.count == 0 => {
+                return Ok(values)
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Reads `E` values from `reader` until one matches `is_sentinel`, such as a directory entry whose first byte is zero.
+/// The sentinel is consumed but not returned.  Reaching the end of the stream cleanly on a value boundary also ends the
+/// sequence, as it does for `read_all`, and as there, a value other than the sentinel that reads no bytes is an
+/// `InvalidInput` error.
+pub fn read_until_sentinel<R, E, F>(reader: &mut R, is_sentinel: F) -> io::Result<Vec<E>>
+where
+    R: Read + ?Sized,
+    E: EndianRead,
+    F: Fn(&E) -> bool,
+{
+    let mut values = Vec::new();
+    loop {
+        let mut counted = CountingReader {
+            inner: &mut *reader,
+            count: 0,
+        };
+        match E::read_from(&mut counted) {
+            Ok(value) if is_sentinel(&value) => return Ok(values),
+            Ok(_) if counted.count == 0 => return Err(empty_value()),
+            Ok(value) => values.push(value),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof && counted.count == 0 => {
+                return Ok(values)
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Counts the bytes read through it, so that a clean end of stream can be told apart from a truncated value.
+struct CountingReader<'a, R: Read + ?Sized> {
+    inner: &'a mut R,
+    count: usize,
+}
+
+impl<R: Read + ?Sized> Read for CountingReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.count += n;
+        Ok(n)
+    }
+}
+
+/// A reader that fails with `InvalidData` ("read limit exceeded") if more than `limit` bytes would be read from it.  This
+/// bounds the total size of everything read from an untrusted source.  The error is deliberately not `UnexpectedEof`, so
+/// `read_all` and `read_until_sentinel` report a stream cut off by the limit rather than treating it as a clean end.  An
+/// inner reader that ends exactly at the limit still reads as a normal end of stream; telling the two apart takes one
+/// byte from the inner reader.
+#[derive(Debug)]
+pub struct LimitedReader<R> {
+    inner: R,
+    remaining: u64,
+}
+
+impl<R: Read> LimitedReader<R> {
+    /// Wraps `inner`, allowing at most `limit` bytes to be read.
+    pub fn new(inner: R, limit: u64) -> Self {
+        Self {
+            inner,
+            remaining: limit,
+        }
+    }
+
+    /// The number of bytes that can still be read.
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Returns the inner reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for LimitedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if self.remaining == 0 {
+            return match self.inner.read(&mut [0])? {
+                0 => Ok(0),
+                _ => Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "read limit exceeded",
+                )),
+            };
+        }
+        let max = buf
+            .len()
+            .min(usize::try_from(self.remaining).unwrap_or(usize::MAX));
+        let n = self.inner.read(&mut buf[..max])?;
+        self.remaining -= n as u64;
+        Ok(n)
+    }
+}
+
+/// Reads an `E` from `reader`, failing with `InvalidData` before anything is read if `E` is bigger than the rest of the
+/// budget.
+pub fn read_specific_limited<R: Read, E: EndianRead + WireSize>(
+    reader: &mut LimitedReader<R>,
+) -> io::Result<E> {
+    if E::WIRE_SIZE as u64 > reader.remaining {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "value is larger than the remaining read limit",
+        ));
+    }
+    E::read_from(reader)
+}
+
+/// Limits on what a count-prefixed read will accept, so that a corrupt or hostile count is rejected before anything is
+/// allocated for it.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ReadLimits {
+    /// The largest number of values accepted.
+    pub max_count: usize,
+    /// The largest total size of the values, in bytes.
+    pub max_bytes: usize,
+}
+
+impl Default for ReadLimits {
+    /// At most 65536 values, taking up at most 1 MiB.
+    fn default() -> Self {
+        Self {
+            max_count: 1 << 16,
+            max_bytes: 1 << 20,
+        }
+    }
+}
+
+/// Reads a count of type `C`, followed by that many `E` values, rejecting counts over the default `ReadLimits`.
+pub fn read_counted<R, C, E>(reader: &mut R) -> io::Result<Vec<E>>
+where
+    R: Read + ?Sized,
+    C: LengthPrefix + EndianRead,
+    E: EndianRead + WireSize,
+{
+    read_counted_with_limits::<R, C, E>(reader, ReadLimits::default())
+}
+
+/// Reads a count of type `C`, followed by that many `E` values.  A count over `limits`, or one that doesn't fit in a
+/// `usize`, is an `InvalidData` error, and nothing is read past the count.
+pub fn read_counted_with_limits<R, C, E>(reader: &mut R, limits: ReadLimits) -> io::Result<Vec<E>>
+where
+    R: Read + ?Sized,
+    C: LengthPrefix + EndianRead,
+    E: EndianRead + WireSize,
+{
+    let count = C::read_from(reader)?
+        .to_len()
+        .filter(|count| *count <= limits.max_count)
+        .filter(|count| {
+            count
+                .checked_mul(E::WIRE_SIZE)
+                .is_some_and(|bytes| bytes <= limits.max_bytes)
+        })
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "count exceeds read limits"))?;
+    let mut values = Vec::with_capacity(count);
+    for _ in 0..count {
+        values.push(E::read_from(reader)?);
+    }
+    Ok(values)
+}
+
+/// Writes the number of `values` as a `C`, followed by the values.  Fails with `InvalidInput` if the count doesn't fit
+/// in a `C`.
+pub fn write_counted<W, C, E>(writer: &mut W, values: &[E]) -> io::Result<()>
+where
+    W: Write + ?Sized,
+    C: LengthPrefix + EndianWrite,
+    E: EndianWrite,
+{
+    let count = C::from_len(values.len()).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "too many values for the count type",
+        )
+    })?;
+    count.write_to(writer)?;
+    values.write_to(writer)
+}
+
+/// Feeds the bytes `value` writes into `hasher`.  Unlike `Hash`, this depends only on the serialized form, so it comes
+/// out the same on every host, and a `BigEndian<u32>` and a `LittleEndian<u32>` with the same native value hash
+/// differently.
+pub fn wire_hash<H: Hasher, E: EndianWrite + ?Sized>(value: &E, hasher: &mut H) -> io::Result<()> {
+    value.write_to(&mut HashWriter(hasher))
+}
+
+/// Hashes the bytes `value` writes with `DefaultHasher`.  This is stable between hosts, but Rust doesn't promise
+/// `DefaultHasher` stays the same between releases, so use `wire_hash` with a fixed hasher for keys that are stored.
+pub fn wire_hash64<E: EndianWrite + ?Sized>(value: &E) -> io::Result<u64> {
+    let mut hasher = DefaultHasher::new();
+    wire_hash(value, &mut hasher)?;
+    Ok(hasher.finish())
+}
+
+/// Passes everything written through it to a `Hasher`.
+struct HashWriter<'a, H: Hasher>(&'a mut H);
+
+impl<H: Hasher> Write for HashWriter<'_, H> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Binary searches a sorted on-disk table of `count` fixed-size records starting at byte offset `base`, without
+/// loading the whole table.  Like `slice::binary_search`, this returns `Ok` with the index of a matching record, or
+/// `Err` with the index where `key` would be inserted.  A record offset that overflows a `u64` is an `InvalidInput`
+/// error.
+pub fn binary_search_wire<R, E>(
+    reader: &mut R,
+    base: u64,
+    count: usize,
+    key: &E,
+) -> io::Result<Result<usize, usize>>
+where
+    R: Read + Seek + ?Sized,
+    E: EndianRead + WireSize + Ord,
+{
+    let (mut low, mut high) = (0, count);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let offset = mid
+            .checked_mul(E::WIRE_SIZE)
+            .and_then(|offset| u64::try_from(offset).ok())
+            .and_then(|offset| base.checked_add(offset))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "table offset overflows"))?;
+        reader.seek(SeekFrom::Start(offset))?;
+        let record = E::read_from(reader)?;
+        match record.cmp(key) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return Ok(Ok(mid)),
+        }
+    }
+    Ok(Err(low))
+}
+
+/// Reads a tagged record `E`, or skips it if its tag is one this version doesn't know, for streams where newer writers
+/// may add record types.  The tag is first peeked as a `T`, then `E::read_from` is expected to reject an unknown tag with
+/// `InvalidData` without reading past it.  When it does, `size_for_unknown` gives the number of bytes after the tag to
+/// skip, and this returns `None`.  If `size_for_unknown` returns `None`, or if `E` fails after the tag because a known
+/// record is corrupt, the error from `E` is returned unchanged.
+pub fn read_or_skip<R, T, E, F>(reader: &mut R, size_for_unknown: F) -> io::Result<Option<E>>
+where
+    R: Read + Seek + ?Sized,
+    T: EndianRead,
+    E: EndianRead,
+    F: Fn(T) -> Option<usize>,
+{
+    let start = reader.stream_position()?;
+    let mut counted = CountingReader {
+        inner: &mut *reader,
+        count: 0,
+    };
+    let tag = T::read_from(&mut counted)?;
+    let tag_len = counted.count;
+    reader.seek(SeekFrom::Start(start))?;
+
+    let mut counted = CountingReader {
+        inner: &mut *reader,
+        count: 0,
+    };
+    let err = match E::read_from(&mut counted) {
+        Ok(value) => return Ok(Some(value)),
+        // Only a failure within the tag means the tag is unknown.
+        Err(e) if e.kind() == io::ErrorKind::InvalidData && counted.count <= tag_len => e,
+        Err(e) => return Err(e),
+    };
+    match size_for_unknown(tag) {
+        Some(len) => {
+            let end = u64::try_from(tag_len + len)
+                .ok()
+                .and_then(|skip| start.checked_add(skip))
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidInput, "record size overflows")
+                })?;
+            reader.seek(SeekFrom::Start(end))?;
+            Ok(None)
+        }
+        None => Err(err),
+    }
+}
+
+/// A byte order chosen at runtime, for formats where the order isn't known until something has been read.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Endian {
+    Big,
+    Little,
+}
+
+/// Reads a four-byte magic number and looks up the byte order it implies in `known_magics`, for formats like pcap that
+/// mark their byte order with the same magic number written in either order.  Each magic is compared with the first
+/// four bytes in the order they appear in the stream, i.e. as a big-endian `u32`.  Finding no match is an
+/// `InvalidData` error.  The reader is left just past the magic, ready for `read_with_order`.
+pub fn read_bom_or_magic<R: Read + ?Sized>(
+    reader: &mut R,
+    known_magics: &[(u32, Endian)],
+) -> io::Result<Endian> {
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf)?;
+    let magic = u32::from_be_bytes(buf);
+    known_magics
+        .iter()
+        .find(|(known, _)| *known == magic)
+        .map(|(_, order)| *order)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "unknown magic number"))
+}
+
+/// Reads a `T` in the byte order given by `order`, returning it in native endian.  This is useful for mixed-endian
+/// formats, or ones where a header declares the order of the fields that follow it.
+#[cfg(all(feature = "big_endian", feature = "little_endian"))]
+pub fn read_with_order<R, T>(reader: &mut R, order: Endian) -> io::Result<T>
+where
+    R: Read + ?Sized,
+    T: SpecificEndian<T>,
+    BigEndian<T>: EndianRead,
+    LittleEndian<T>: EndianRead,
+{
+    match order {
+        Endian::Big => Ok(read_specific::<R, BigEndian<T>>(reader)?.to_native()),
+        Endian::Little => Ok(read_specific::<R, LittleEndian<T>>(reader)?.to_native()),
+    }
+}
+
+/// Writes a native `value` in the byte order given by `order`.
+#[cfg(all(feature = "big_endian", feature = "little_endian"))]
+pub fn write_with_order<W, T>(writer: &mut W, value: T, order: Endian) -> io::Result<()>
+where
+    W: Write + ?Sized,
+    T: SpecificEndian<T>,
+    BigEndian<T>: EndianWrite,
+    LittleEndian<T>: EndianWrite,
+{
+    match order {
+        Endian::Big => write_specific(writer, &BigEndian::from(value)),
+        Endian::Little => write_specific(writer, &LittleEndian::from(value)),
+    }
+}
+
+/// What `read_canonical_f32` and `read_canonical_f64` do with a NaN that isn't the canonical quiet NaN.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum NanPolicy {
+    /// Fail with `InvalidData`.
+    Reject,
+    /// Replace it with the canonical NaN.
+    Canonicalize,
+}
+
+#[allow(unused_macros)]
+macro_rules! add_read_canonical {
+    ($name:ident, $float_ty:ty) => {
+        /// Reads a float through the wrapper `E`, handling any NaN other than the canonical `NAN` according to
+        /// `policy`.  Formats that need exactly one encoding per value, e.g. for hashing, can use this to allow only a
+        /// single NaN bit pattern.
+        pub fn $name<R, E>(reader: &mut R, policy: NanPolicy) -> io::Result<$float_ty>
+        where
+            R: Read + ?Sized,
+            E: EndianRead + Into<$float_ty>,
+        {
+            let value: $float_ty = E::read_from(reader)?.into();
+            if !value.is_nan() || value.to_bits() == <$float_ty>::NAN.to_bits() {
+                return Ok(value);
+            }
+            match policy {
+                NanPolicy::Reject => Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "non-canonical NaN",
+                )),
+                NanPolicy::Canonicalize => Ok(<$float_ty>::NAN),
+            }
+        }
+    };
+}
+
+#[cfg(feature = "float_impls")]
+add_read_canonical!(read_canonical_f32, f32);
+#[cfg(feature = "float_impls")]
+add_read_canonical!(read_canonical_f64, f64);
+
+/// Implements `EndianRead` and `EndianWrite` for a wrapper over a type that has `from_xx_bytes()`/`to_xx_bytes()`.
+#[allow(unused_macros)]
+macro_rules! add_io_ops {
+    ($wrapper:ident, $native_ty:ty, $from_bytes:ident, $to_bytes:ident) => {
+        impl EndianRead for $wrapper<$native_ty> {
+            fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+                let mut buf = [0u8; core::mem::size_of::<$native_ty>()];
+                reader.read_exact(&mut buf)?;
+                Ok(Self::from(<$native_ty>::$from_bytes(buf)))
+            }
+        }
+
+        impl EndianWrite for $wrapper<$native_ty> {
+            fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+                writer.write_all(&self.to_native().$to_bytes())
+            }
+        }
+
+        impl WireSize for $wrapper<$native_ty> {
+            const WIRE_SIZE: usize = core::mem::size_of::<$native_ty>();
+        }
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! add_be_io_ops {
+    ($native_ty:ty) => {
+        add_io_ops!(BigEndian, $native_ty, from_be_bytes, to_be_bytes);
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! add_le_io_ops {
+    ($native_ty:ty) => {
+        add_io_ops!(LittleEndian, $native_ty, from_le_bytes, to_le_bytes);
+    };
+}
+
+/// `bool` has no `from_xx_bytes()`, and only 0 and 1 are valid, so anything else is rejected on read.
+#[allow(unused_macros)]
+macro_rules! add_bool_io_ops {
+    ($wrapper:ident) => {
+        impl EndianRead for $wrapper<bool> {
+            fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+                let mut buf = [0u8; 1];
+                reader.read_exact(&mut buf)?;
+                match buf[0] {
+                    0 => Ok(Self::from(false)),
+                    1 => Ok(Self::from(true)),
+                    _ => Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "invalid bool value",
+                    )),
+                }
+            }
+        }
+
+        impl EndianWrite for $wrapper<bool> {
+            fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+                writer.write_all(&[self.to_native() as u8])
+            }
+        }
+
+        impl WireSize for $wrapper<bool> {
+            const WIRE_SIZE: usize = 1;
+        }
+    };
+}
+
+#[cfg(feature = "big_endian")]
+mod be {
+    use super::*;
+    #[cfg(feature = "byte_impls")]
+    mod bytes {
+        use super::*;
+        add_bool_io_ops!(BigEndian);
+        add_be_io_ops!(u8);
+        add_be_io_ops!(i8);
+    }
+
+    #[cfg(feature = "integer_impls")]
+    mod integers {
+        use super::*;
+        add_be_io_ops!(u16);
+        add_be_io_ops!(i16);
+        add_be_io_ops!(u32);
+        add_be_io_ops!(i32);
+        add_be_io_ops!(u64);
+        add_be_io_ops!(i64);
+        add_be_io_ops!(u128);
+        add_be_io_ops!(i128);
+        add_be_io_ops!(usize);
+        add_be_io_ops!(isize);
+    }
+
+    #[cfg(feature = "float_impls")]
+    mod floats {
+        use super::*;
+        add_be_io_ops!(f32);
+        add_be_io_ops!(f64);
+    }
+}
+
+#[cfg(feature = "little_endian")]
+mod le {
+    use super::*;
+    #[cfg(feature = "byte_impls")]
+    mod bytes {
+        use super::*;
+        add_bool_io_ops!(LittleEndian);
+        add_le_io_ops!(u8);
+        add_le_io_ops!(i8);
+    }
+
+    #[cfg(feature = "integer_impls")]
+    mod integers {
+        use super::*;
+        add_le_io_ops!(u16);
+        add_le_io_ops!(i16);
+        add_le_io_ops!(u32);
+        add_le_io_ops!(i32);
+        add_le_io_ops!(u64);
+        add_le_io_ops!(i64);
+        add_le_io_ops!(u128);
+        add_le_io_ops!(i128);
+        add_le_io_ops!(usize);
+        add_le_io_ops!(isize);
+    }
+
+    #[cfg(feature = "float_impls")]
+    mod floats {
+        use super::*;
+        add_le_io_ops!(f32);
+        add_le_io_ops!(f64);
+    }
+}
+
+#[cfg(feature = "native_endian")]
+mod ne {
+    use super::*;
+
+    #[allow(unused_macros)]
+    macro_rules! add_ne_io_ops {
+        ($native_ty:ty) => {
+            add_io_ops!(NativeEndian, $native_ty, from_ne_bytes, to_ne_bytes);
+        };
+    }
+
+    #[cfg(feature = "byte_impls")]
+    mod bytes {
+        use super::*;
+        add_bool_io_ops!(NativeEndian);
+        add_ne_io_ops!(u8);
+        add_ne_io_ops!(i8);
+    }
+
+    #[cfg(feature = "integer_impls")]
+    mod integers {
+        use super::*;
+        add_ne_io_ops!(u16);
+        add_ne_io_ops!(i16);
+        add_ne_io_ops!(u32);
+        add_ne_io_ops!(i32);
+        add_ne_io_ops!(u64);
+        add_ne_io_ops!(i64);
+        add_ne_io_ops!(u128);
+        add_ne_io_ops!(i128);
+        add_ne_io_ops!(usize);
+        add_ne_io_ops!(isize);
+    }
+
+    #[cfg(feature = "float_impls")]
+    mod floats {
+        use super::*;
+        add_ne_io_ops!(f32);
+        add_ne_io_ops!(f64);
+    }
+}
+
+/// Tuples read and write their elements in order, with nothing between them.
+macro_rules! add_tuple_io_ops {
+    ($($name:ident)+) => {
+        impl<$($name: EndianRead),+> EndianRead for ($($name,)+) {
+            fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+                Ok(($($name::read_from(reader)?,)+))
+            }
+        }
+
+        impl<$($name: EndianWrite),+> EndianWrite for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+                let ($($name,)+) = self;
+                $($name.write_to(writer)?;)+
+                Ok(())
+            }
+        }
+
+        impl<$($name: WireSize),+> WireSize for ($($name,)+) {
+            const WIRE_SIZE: usize = 0 $(+ $name::WIRE_SIZE)+;
+        }
+    };
+}
+
+add_tuple_io_ops!(A);
+add_tuple_io_ops!(A B);
+add_tuple_io_ops!(A B C);
+add_tuple_io_ops!(A B C D);
+add_tuple_io_ops!(A B C D E);
+add_tuple_io_ops!(A B C D E F);
+add_tuple_io_ops!(A B C D E F G);
+add_tuple_io_ops!(A B C D E F G H);
+
+/// Ranges are written as their start followed by their end, e.g. for extents or bounding intervals.  The element type
+/// carries the byte order, as in `Range<u32be>`.
+impl<E: EndianRead> EndianRead for Range<E> {
+    fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+        let start = E::read_from(reader)?;
+        let end = E::read_from(reader)?;
+        Ok(start..end)
+    }
+}
+
+impl<E: EndianWrite> EndianWrite for Range<E> {
+    fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        self.start.write_to(writer)?;
+        self.end.write_to(writer)
+    }
+}
+
+impl<E: WireSize> WireSize for Range<E> {
+    const WIRE_SIZE: usize = 2 * E::WIRE_SIZE;
+}
+
+impl<E: EndianRead> EndianRead for RangeInclusive<E> {
+    fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+        let start = E::read_from(reader)?;
+        let end = E::read_from(reader)?;
+        Ok(start..=end)
+    }
+}
+
+impl<E: EndianWrite> EndianWrite for RangeInclusive<E> {
+    fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        self.start().write_to(writer)?;
+        self.end().write_to(writer)
+    }
+}
+
+impl<E: WireSize> WireSize for RangeInclusive<E> {
+    const WIRE_SIZE: usize = 2 * E::WIRE_SIZE;
+}
+
+/// `Saturating` is written as its inner value, so the inner type carries the byte order, as in `Saturating<u16le>`.
+impl<E: EndianRead> EndianRead for Saturating<E> {
+    fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+        Ok(Saturating(E::read_from(reader)?))
+    }
+}
+
+impl<E: EndianWrite> EndianWrite for Saturating<E> {
+    fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        self.0.write_to(writer)
+    }
+}
+
+impl<E: WireSize> WireSize for Saturating<E> {
+    const WIRE_SIZE: usize = E::WIRE_SIZE;
+}
+
+/// IP addresses are stored as their octets, which are in network (big-endian) order by definition.
+mod net {
+    use super::*;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    impl EndianRead for Ipv4Addr {
+        fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+            let mut octets = [0u8; 4];
+            reader.read_exact(&mut octets)?;
+            Ok(Self::from(octets))
+        }
+    }
+
+    impl EndianWrite for Ipv4Addr {
+        fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+            writer.write_all(&self.octets())
+        }
+    }
+
+    impl WireSize for Ipv4Addr {
+        const WIRE_SIZE: usize = 4;
+    }
+
+    impl EndianRead for Ipv6Addr {
+        fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+            let mut octets = [0u8; 16];
+            reader.read_exact(&mut octets)?;
+            Ok(Self::from(octets))
+        }
+    }
+
+    impl EndianWrite for Ipv6Addr {
+        fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+            writer.write_all(&self.octets())
+        }
+    }
+
+    impl WireSize for Ipv6Addr {
+        const WIRE_SIZE: usize = 16;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::io::{self, Cursor, Read, Write};
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    #[test]
+    fn write_be() {
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &u32be::from(0x12345678)).unwrap();
+        assert_eq!(buf, [0x12, 0x34, 0x56, 0x78]);
+    }
+
+    #[test]
+    fn write_le() {
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &u32le::from(0x12345678)).unwrap();
+        assert_eq!(buf, [0x78, 0x56, 0x34, 0x12]);
+    }
+
+    #[test]
+    fn roundtrip_fp_be() {
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &f64be::from(1234.5678)).unwrap();
+        assert_eq!(buf, 1234.5678f64.to_be_bytes());
+        let be: f64be = read_specific(&mut Cursor::new(buf)).unwrap();
+        assert_eq!(be.to_native(), 1234.5678);
+    }
+
+    #[test]
+    fn write_through_refs() {
+        let be = BigEndian::<u32>::from(0x12345678);
+        let by_ref = &be;
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &by_ref).unwrap();
+        write_specific(&mut buf, &&by_ref).unwrap();
+        assert_eq!(buf, [0x12, 0x34, 0x56, 0x78, 0x12, 0x34, 0x56, 0x78]);
+    }
+
+    #[test]
+    fn write_padded_sector() {
+        let label = FixedBytes(*b"NO NAME    ");
+        let mut sector = Vec::new();
+        write_specific_padded(&mut sector, &label, 512, 0).unwrap();
+        assert_eq!(sector.len(), 512);
+        assert_eq!(&sector[..11], b"NO NAME    ");
+        assert!(sector[11..].iter().all(|b| *b == 0));
+
+        let mut buf = Vec::new();
+        let err = write_specific_padded(&mut buf, &u64be::from(1), 4, 0xff).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn write_slice() {
+        let values: Vec<BigEndian<u16>> = vec![1.into(), 0x203.into(), 0xffff.into()];
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &values[..]).unwrap();
+        write_specific(&mut buf, &values[..0]).unwrap();
+        assert_eq!(buf, [0x00, 0x01, 0x02, 0x03, 0xff, 0xff]);
+    }
+
+    #[test]
+    fn wire_hash_matches_bytes() {
+        use core::hash::Hasher;
+        use std::collections::hash_map::DefaultHasher;
+
+        // Whatever the host order, the hash is the hash of the bytes on the wire.
+        let mut expected = DefaultHasher::new();
+        expected.write(&[0x12, 0x34, 0x56, 0x78, 0x00, 0x01]);
+        let expected = expected.finish();
+        let parts = (u32be::from(0x12345678), u16be::from(1));
+        let mut hasher = DefaultHasher::new();
+        wire_hash(&parts.0, &mut hasher).unwrap();
+        wire_hash(&parts.1, &mut hasher).unwrap();
+        assert_eq!(hasher.finish(), expected);
+
+        let read: u32be = read_specific(&mut &[0x12u8, 0x34, 0x56, 0x78][..]).unwrap();
+        assert_eq!(wire_hash64(&read).unwrap(), wire_hash64(&parts.0).unwrap());
+        assert_ne!(
+            wire_hash64(&u32le::from(0x12345678)).unwrap(),
+            wire_hash64(&parts.0).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_prefix_chained() {
+        let frame = [0x08, 0x00, 0x45, 0x00, 0x00, 0x54, 0xde, 0xad];
+        let (ethertype, rest) = parse_prefix::<u16be>(&frame).unwrap();
+        let (version_len, rest) = parse_prefix::<BigEndian<u8>>(rest).unwrap();
+        let (_, rest) = parse_prefix::<BigEndian<u8>>(rest).unwrap();
+        let (total_len, rest) = parse_prefix::<u16be>(rest).unwrap();
+        assert_eq!(ethertype.to_native(), 0x0800);
+        assert_eq!(version_len.to_native(), 0x45);
+        assert_eq!(total_len.to_native(), 84);
+        assert_eq!(rest, [0xde, 0xad]);
+        assert!(parse_prefix::<u32be>(rest).is_err());
+    }
+
+    #[test]
+    fn roundtrip_tuple() {
+        let data = [b'R', b'I', b'F', b'F', 0x24, 0, 0, 0, 0x00, 0x01];
+        let (magic, size, version): (FixedBytes<4>, u32le, u16be) =
+            read_specific(&mut &data[..]).unwrap();
+        assert_eq!(magic, FixedBytes(*b"RIFF"));
+        assert_eq!(size.to_native(), 0x24);
+        assert_eq!(version.to_native(), 1);
+        assert_eq!(<(FixedBytes<4>, u32le, u16be)>::WIRE_SIZE, 10);
+
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &(magic, size, version)).unwrap();
+        assert_eq!(buf, data);
+    }
+
+    #[test]
+    fn skip_unknown_records() {
+        // Tag 1 is a known record with a u32 payload.  Tags 2 and 3 are from a newer version, and only the size of
+        // tag 2 is known to this reader.
+        #[derive(Debug, PartialEq)]
+        struct Ping(u32be);
+
+        impl EndianRead for Ping {
+            fn read_from<R: io::Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+                match read_specific::<_, u16be>(reader)?.to_native() {
+                    1 => Ok(Ping(read_specific(reader)?)),
+                    _ => Err(io::Error::new(io::ErrorKind::InvalidData, "unknown tag")),
+                }
+            }
+        }
+
+        let data = [
+            0, 1, 0, 0, 0, 7, // Ping(7)
+            0, 2, 0, 0, 0, 0, 0, 0, 0, 0, // unknown tag 2, eight bytes
+            0, 1, 0, 0, 0, 8, // Ping(8)
+            0, 3, 0xff, // unknown tag 3, with no known size
+        ];
+        let size_for_unknown = |tag: u16be| match tag.to_native() {
+            2 => Some(8),
+            _ => None,
+        };
+        let mut stream = Cursor::new(&data[..]);
+        let mut read = || read_or_skip::<_, u16be, Ping, _>(&mut stream, size_for_unknown);
+        assert_eq!(read().unwrap(), Some(Ping(7.into())));
+        assert_eq!(read().unwrap(), None);
+        assert_eq!(read().unwrap(), Some(Ping(8.into())));
+        assert_eq!(read().unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn roundtrip_smart_pointers() {
+        let mut data = vec![0xee; 4096];
+        data.extend_from_slice(&[0, 0, 0, 9]);
+        let block: Box<(FixedBytes<4096>, u32be)> = read_specific(&mut &data[..]).unwrap();
+        assert_eq!(block.0, FixedBytes([0xee; 4096]));
+        assert_eq!(block.1.to_native(), 9);
+
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &block).unwrap();
+        assert_eq!(buf, data);
+
+        let shared: std::rc::Rc<[u16le]> = vec![1.into(), 2.into()].into();
+        let sync = std::sync::Arc::new(u16be::from(3));
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &shared).unwrap();
+        write_specific(&mut buf, &sync).unwrap();
+        assert_eq!(buf, [1, 0, 2, 0, 0, 3]);
+    }
+
+    #[test]
+    fn roundtrip_ranges() {
+        let extent = u32be::from(0x1000)..u32be::from(0x2000);
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &extent).unwrap();
+        assert_eq!(buf, [0, 0, 0x10, 0, 0, 0, 0x20, 0]);
+        let read: core::ops::Range<u32be> = read_specific(&mut &buf[..]).unwrap();
+        let native = read.start.to_native()..read.end.to_native();
+        assert_eq!(native, 0x1000..0x2000);
+
+        let bounds = i16le::from(-5)..=i16le::from(5);
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &bounds).unwrap();
+        assert_eq!(buf, [0xfb, 0xff, 0x05, 0x00]);
+        let read: core::ops::RangeInclusive<i16le> = read_specific(&mut &buf[..]).unwrap();
+        assert_eq!(read, bounds);
+    }
+
+    #[test]
+    fn read_to_sentinel() {
+        // Two-byte entries, ending at an entry that starts with zero.
+        let mut data: &[u8] = &[b'A', 1, b'B', 2, 0, 0, b'C', 3];
+        let entries = read_until_sentinel(&mut data, |e: &FixedBytes<2>| e.0[0] == 0).unwrap();
+        assert_eq!(entries, [FixedBytes([b'A', 1]), FixedBytes([b'B', 2])]);
+        assert_eq!(data, [b'C', 3]);
+
+        let mut data: &[u8] = &[0, 1, 0, 2];
+        let entries = read_until_sentinel(&mut data, |e: &u16be| e.to_native() == 0).unwrap();
+        assert_eq!(entries, [1.into(), 2.into()]);
+    }
+
+    #[test]
+    fn read_truncated() {
+        let err = read_specific::<_, u64le>(&mut &[1u8, 2, 3][..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn read_dyn() {
+        let mut data: &[u8] = &[0x00, 0x2a];
+        let reader: &mut dyn io::Read = &mut data;
+        let be: u16be = read_specific(reader).unwrap();
+        assert_eq!(be.to_native(), 42);
+    }
+
+    #[test]
+    fn read_invalid_bool() {
+        let err = read_specific::<_, BigEndian<bool>>(&mut &[2u8][..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn read_signaling_nan() {
+        // A signaling NaN: exponent all ones, quiet bit clear, nonzero payload.
+        let snan = 0x7f80_0001u32.to_be_bytes();
+        let err = read_canonical_f32::<_, f32be>(&mut &snan[..], NanPolicy::Reject).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let value =
+            read_canonical_f32::<_, f32be>(&mut &snan[..], NanPolicy::Canonicalize).unwrap();
+        assert_eq!(value.to_bits(), f32::NAN.to_bits());
+
+        let snan = 0x7ff0_0000_0000_0001u64.to_le_bytes();
+        let value =
+            read_canonical_f64::<_, f64le>(&mut &snan[..], NanPolicy::Canonicalize).unwrap();
+        assert_eq!(value.to_bits(), f64::NAN.to_bits());
+        let canonical = f64::NAN.to_le_bytes();
+        assert!(
+            read_canonical_f64::<_, f64le>(&mut &canonical[..], NanPolicy::Reject)
+                .unwrap()
+                .is_nan()
+        );
+        let one = 1.0f64.to_le_bytes();
+        assert_eq!(
+            read_canonical_f64::<_, f64le>(&mut &one[..], NanPolicy::Reject).unwrap(),
+            1.0
+        );
+    }
+
+    #[test]
+    fn detect_pcap_magic() {
+        const PCAP_MAGICS: [(u32, Endian); 2] =
+            [(0xa1b2c3d4, Endian::Big), (0xd4c3b2a1, Endian::Little)];
+        let mut le_file: &[u8] = &[0xd4, 0xc3, 0xb2, 0xa1, 0x02, 0x00];
+        let order = read_bom_or_magic(&mut le_file, &PCAP_MAGICS).unwrap();
+        assert_eq!(order, Endian::Little);
+        assert_eq!(read_with_order::<_, u16>(&mut le_file, order).unwrap(), 2);
+
+        let mut be_file: &[u8] = &[0xa1, 0xb2, 0xc3, 0xd4, 0x00, 0x02];
+        let order = read_bom_or_magic(&mut be_file, &PCAP_MAGICS).unwrap();
+        assert_eq!(order, Endian::Big);
+        assert_eq!(read_with_order::<_, u16>(&mut be_file, order).unwrap(), 2);
+
+        let err = read_bom_or_magic(&mut &[0u8, 0, 0, 0][..], &PCAP_MAGICS).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn read_mixed_order() {
+        // A little-endian field followed by a big-endian one, both read with the same function.
+        let mut data: &[u8] = &[0x01, 0x02, 0x01, 0x02];
+        let le: u16 = read_with_order(&mut data, Endian::Little).unwrap();
+        let be: u16 = read_with_order(&mut data, Endian::Big).unwrap();
+        assert_eq!(le, 0x0201);
+        assert_eq!(be, 0x0102);
+    }
+
+    #[test]
+    fn write_mixed_order() {
+        let mut buf = Vec::new();
+        write_with_order(&mut buf, 0x0102u16, Endian::Little).unwrap();
+        write_with_order(&mut buf, 0x0102u16, Endian::Big).unwrap();
+        assert_eq!(buf, [0x02, 0x01, 0x01, 0x02]);
+    }
+
+    #[test]
+    fn read_all_records() {
+        let mut data: &[u8] = &[0x00, 0x01, 0x00, 0x02, 0x00, 0x03];
+        let records: Vec<u16be> = read_all(&mut data).unwrap();
+        assert_eq!(records, [1.into(), 2.into(), 3.into()]);
+    }
+
+    #[test]
+    fn read_all_truncated() {
+        let mut data: &[u8] = &[0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00];
+        let err = read_all::<_, u16be>(&mut data).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn roundtrip_counted() {
+        let values: [u16le; 3] = [1.into(), 2.into(), 0x300.into()];
+        let mut buf = Vec::new();
+        write_counted::<_, u32be, _>(&mut buf, &values).unwrap();
+        assert_eq!(buf, [0, 0, 0, 3, 1, 0, 2, 0, 0, 3]);
+        let read = read_counted::<_, u32be, u16le>(&mut &buf[..]).unwrap();
+        assert_eq!(read, values);
+    }
+
+    #[test]
+    fn read_counted_oversized() {
+        // A hostile count with no data behind it must be rejected, not allocated.
+        let mut data: &[u8] = &[0xff, 0xff, 0xff, 0xff];
+        let err = read_counted::<_, u32be, u64be>(&mut data).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let limits = ReadLimits {
+            max_count: 100,
+            max_bytes: 15,
+        };
+        let mut data: &[u8] = &[2, 0, 0, 0, 0, 0, 0, 0, 1];
+        let err =
+            read_counted_with_limits::<_, BigEndian<u8>, u64be>(&mut data, limits).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(data.len(), 8);
+    }
+
+    #[cfg(feature = "native_endian")]
+    #[test]
+    fn write_native() {
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &NativeEndian::<u32>::from(0x12345678)).unwrap();
+        assert_eq!(buf, 0x12345678u32.to_ne_bytes());
+        let ne: NativeEndian<u32> = read_specific(&mut &buf[..]).unwrap();
+        assert_eq!(ne.to_native(), 0x12345678);
+    }
+
+    #[test]
+    fn roundtrip_ipv4() {
+        let addr = Ipv4Addr::new(192, 168, 1, 20);
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &addr).unwrap();
+        assert_eq!(buf, [192, 168, 1, 20]);
+        assert_eq!(read_specific::<_, Ipv4Addr>(&mut &buf[..]).unwrap(), addr);
+    }
+
+    #[test]
+    fn roundtrip_ipv6() {
+        let addr = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &addr).unwrap();
+        assert_eq!(buf[..4], [0x20, 0x01, 0x0d, 0xb8]);
+        assert_eq!(buf[15], 1);
+        assert_eq!(read_specific::<_, Ipv6Addr>(&mut &buf[..]).unwrap(), addr);
+    }
+
+    #[test]
+    fn binary_search_table() {
+        // A four-byte header, then a sorted table of keys.
+        let keys = [3u32, 8, 15, 42, 99, 1000];
+        let mut buf = vec![0xff; 4];
+        for k in keys.iter() {
+            write_specific(&mut buf, &u32be::from(*k)).unwrap();
+        }
+        let mut table = Cursor::new(buf);
+        for (i, k) in keys.iter().enumerate() {
+            let found = binary_search_wire(&mut table, 4, keys.len(), &u32be::from(*k)).unwrap();
+            assert_eq!(found, Ok(i));
+        }
+        let search = |table: &mut Cursor<Vec<u8>>, k: u32| {
+            binary_search_wire(table, 4, keys.len(), &u32be::from(k)).unwrap()
+        };
+        assert_eq!(search(&mut table, 0), Err(0));
+        assert_eq!(search(&mut table, 16), Err(3));
+        assert_eq!(search(&mut table, 5000), Err(6));
+        assert_eq!(
+            binary_search_wire(&mut table, 4, 0, &u32be::from(8)).unwrap(),
+            Err(0)
+        );
+    }
+
+    #[test]
+    fn transcode_header() {
+        struct Header<W, H> {
+            magic: W,
+            version: H,
+        }
+        impl<W: EndianRead, H: EndianRead> EndianRead for Header<W, H> {
+            fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+                Ok(Self {
+                    magic: W::read_from(reader)?,
+                    version: H::read_from(reader)?,
+                })
+            }
+        }
+        impl<W: EndianWrite, H: EndianWrite> EndianWrite for Header<W, H> {
+            fn write_to<Wr: Write + ?Sized>(&self, writer: &mut Wr) -> io::Result<()> {
+                self.magic.write_to(writer)?;
+                self.version.write_to(writer)
+            }
+        }
+        impl From<Header<u32be, u16be>> for Header<u32le, u16le> {
+            fn from(h: Header<u32be, u16be>) -> Self {
+                Self {
+                    magic: h.magic.into(),
+                    version: h.version.into(),
+                }
+            }
+        }
+
+        let be = [0xca, 0xfe, 0xf0, 0x0d, 0x00, 0x02];
+        let mut le = Vec::new();
+        transcode::<_, _, Header<u32be, u16be>, Header<u32le, u16le>>(&mut &be[..], &mut le)
+            .unwrap();
+        assert_eq!(le, [0x0d, 0xf0, 0xfe, 0xca, 0x02, 0x00]);
+
+        let mut out = Vec::new();
+        transcode::<_, _, u64le, u64be>(&mut &[1u8, 0, 0, 0, 0, 0, 0, 0][..], &mut out).unwrap();
+        assert_eq!(out, [0, 0, 0, 0, 0, 0, 0, 1]);
+        assert!(transcode::<_, _, u64le, u64be>(&mut &[1u8][..], &mut out).is_err());
+    }
+
+    #[test]
+    fn roundtrip_text_array() {
+        // A directory of four fixed-width names, with NUL padding and trailing junk that must survive as-is.
+        let mut buf = Vec::new();
+        for name in [
+            &b"BOOT\0\0\0\0"[..],
+            b"KERNEL\0x",
+            b"INITRD\0\0",
+            b"CONFIG.T",
+        ]
+        .iter()
+        {
+            buf.extend_from_slice(name);
+        }
+        let names: [CStrField<8>; 4] = read_specific(&mut &buf[..]).unwrap();
+        assert_eq!(names[1].as_str(), Ok("KERNEL"));
+        assert_eq!(names[3].as_str(), Ok("CONFIG.T"));
+        assert_eq!(<[CStrField<8>; 4]>::WIRE_SIZE, 32);
+
+        let mut out = Vec::new();
+        write_specific(&mut out, &names).unwrap();
+        assert_eq!(out, buf);
+        assert!(read_specific::<_, [CStrField<8>; 4]>(&mut &buf[..31]).is_err());
+
+        // Elements that aren't Copy.
+        let boxed: [Box<u16be>; 2] = read_specific(&mut &[0u8, 1, 0, 2][..]).unwrap();
+        assert_eq!(*boxed[1], 2.into());
+    }
+
+    #[test]
+    fn read_limit_mid_struct() {
+        let data = [0u8, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3];
+        let mut reader = LimitedReader::new(&data[..], 10);
+        let first: (u32be, u32be) = read_specific(&mut reader).unwrap();
+        assert_eq!(first, (1.into(), 2.into()));
+        assert_eq!(reader.remaining(), 2);
+
+        // Nothing is consumed when the value can't fit.
+        let err = read_specific_limited::<_, u32be>(&mut reader).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(reader.remaining(), 2);
+
+        // A plain read stops at the limit, partway through the value.
+        let err = read_specific::<_, u32be>(&mut reader).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "read limit exceeded");
+        assert_eq!(reader.remaining(), 0);
+    }
+
+    #[test]
+    fn read_all_limited() {
+        // Hitting the limit is an error, not the end of the stream.
+        let data = [0u8, 1, 0, 2, 0, 3];
+        let err = read_all::<_, u16be>(&mut LimitedReader::new(&data[..], 4)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let err = read_until_sentinel(&mut LimitedReader::new(&data[..], 4), |v: &u16be| {
+            v.to_native() == 9
+        })
+        .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        // A stream that ends exactly at the limit is fine.
+        let values: Vec<u16be> = read_all(&mut LimitedReader::new(&data[..], 6)).unwrap();
+        assert_eq!(values, [1.into(), 2.into(), 3.into()]);
+    }
+
+    #[test]
+    fn read_whole_truncated() {
+        let data = [0u8, 0, 0, 7, 0, 9];
+        let value: (u32be, u16be) = read_specific_whole(&mut &data[..]).unwrap();
+        assert_eq!(value, (7.into(), 9.into()));
+
+        let err = read_specific_whole::<_, (u32be, u32be)>(&mut &data[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(err.to_string(), "needed 8 bytes, got 6");
+    }
+
+    #[test]
+    fn roundtrip_hex() {
+        let header = (
+            u32be::from(0xcafef00d),
+            u16le::from(2),
+            BigEndian::from(true),
+        );
+        let hex = to_hex(&header).unwrap();
+        assert_eq!(hex, "cafef00d020001");
+        assert_eq!(
+            from_hex::<(u32be, u16le, BigEndian<bool>)>(&hex),
+            Ok(header)
+        );
+        assert_eq!(from_hex::<u32be>("CAFEF00D"), Ok(0xcafef00d.into()));
+
+        assert!(from_hex::<u32be>("cafef00").is_err());
+        assert!(from_hex::<u32be>("cafef0").is_err());
+        assert!(from_hex::<u32be>("cafef00d00").is_err());
+        assert!(from_hex::<u32be>("cafef00g").is_err());
+        assert!(from_hex::<BigEndian<bool>>("02").is_err());
+    }
+
+    #[test]
+    fn wire_size_stack_buffer() {
+        type Header = (u32be, u16le, [BigEndian<u8>; 2]);
+        let mut buf = [0u8; <Header>::WIRE_SIZE];
+        let header: Header = (0xcafef00d.into(), 2.into(), [1.into(), 2.into()]);
+        write_specific(&mut &mut buf[..], &header).unwrap();
+        assert_eq!(buf, [0xca, 0xfe, 0xf0, 0x0d, 0x02, 0x00, 0x01, 0x02]);
+        assert_eq!(read_specific::<_, Header>(&mut &buf[..]).unwrap(), header);
+    }
+
+    #[test]
+    fn read_all_zero_size() {
+        let err = read_all::<_, FixedBytes<0>>(&mut &[1u8, 2][..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let err = read_all::<_, [u16be; 0]>(&mut &[][..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn read_to_sentinel_zero_size() {
+        let err = read_until_sentinel(&mut &[1u8][..], |_: &FixedBytes<0>| false).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let values = read_until_sentinel(&mut &[1u8][..], |_: &FixedBytes<0>| true).unwrap();
+        assert!(values.is_empty());
+    }
+
+    #[test]
+    fn binary_search_overflow() {
+        let mut table = Cursor::new(vec![0u8; 16]);
+        let err = binary_search_wire(&mut table, u64::MAX - 4, 4, &u32be::from(1)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let err =
+            binary_search_wire(&mut table, 0, usize::MAX, &FixedBytes([0u8; 16])).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn read_or_skip_corrupt_known_record() {
+        // Tag 1 is a known record holding a bool, and the size table covers every tag, known or not.
+        #[derive(Debug, PartialEq)]
+        struct Flag(BigEndian<bool>);
+
+        impl EndianRead for Flag {
+            fn read_from<R: io::Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+                match read_specific::<_, u16be>(reader)?.to_native() {
+                    1 => Ok(Flag(read_specific(reader)?)),
+                    _ => Err(io::Error::new(io::ErrorKind::InvalidData, "unknown tag")),
+                }
+            }
+        }
+
+        let data = [0, 1, 7, 0, 2, 0xff, 0, 1, 1];
+        let size_for_unknown = |_: u16be| Some(1);
+        let mut stream = Cursor::new(&data[..]);
+        let read = |stream: &mut Cursor<&[u8]>| {
+            read_or_skip::<_, u16be, Flag, _>(stream, size_for_unknown)
+        };
+        let err = read(&mut stream).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(stream.position(), 3);
+        assert_eq!(read(&mut stream).unwrap(), None);
+        assert_eq!(read(&mut stream).unwrap(), Some(Flag(true.into())));
+    }
+}
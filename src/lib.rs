@@ -108,17 +108,33 @@ fn main() -> Result<(), Error> {
 ```
 
 */
-#[warn(soft_unstable)]
+#[cfg(feature = "io-std")]
+extern crate std;
 
+#[warn(soft_unstable)]
 /// The main part of the library.  Contains the trait SpecificEndian<T> and BigEndian<T> and LittleEndian<T> structs, as well as the
 /// implementation of those on the primitive types.
 mod specific_endian;
 pub use specific_endian::*;
 
+/// Parsing from byte slices, without `std`.
+mod slice_parse;
+pub use slice_parse::*;
+
 /// Bitwise operations.  These should be equally fast in any endian.
 #[cfg(feature = "bitwise")]
 mod bitwise_ops;
 
+/// Hashing of the float types by their canonical bits.
+#[cfg(feature = "float_hash")]
+mod float_hash;
+#[cfg(feature = "float_hash")]
+pub use float_hash::*;
+
+/// Volatile access to specific-endian values in mapped memory.
+mod volatile;
+pub use volatile::*;
+
 /// Ops for comparisons and ordering.
 #[cfg(feature = "comparisons")]
 mod comparison_ops;
@@ -130,6 +146,8 @@ mod shift_ops;
 /// General math operations.
 #[cfg(feature = "math_ops")]
 mod math_ops;
+#[cfg(feature = "math_ops")]
+pub use math_ops::*;
 
 /// Negations.
 #[cfg(feature = "neg_ops")]
@@ -139,6 +157,145 @@ mod neg_ops;
 #[cfg(feature = "format")]
 mod formatting_ops;
 
+/// Reading and writing the endian types with `std::io`.
+#[cfg(feature = "io-std")]
+mod io;
+#[cfg(feature = "io-std")]
+pub use io::*;
+
+/// Timestamps made of a seconds field and a sub-second field.
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "time")]
+pub use time::*;
+
+/// Reading values at an offset in a byte buffer, such as a memory-mapped file.
+#[cfg(feature = "io-mmap")]
+mod view;
+#[cfg(feature = "io-mmap")]
+pub use view::*;
+
+/// Parsers for using the endian types with `nom`.
+#[cfg(feature = "nom")]
+mod parsers;
+#[cfg(feature = "nom")]
+pub use parsers::*;
+
+/// A length-delimited framing codec for `tokio_util`.
+#[cfg(feature = "io-tokio-codec")]
+mod codec;
+#[cfg(feature = "io-tokio-codec")]
+pub use codec::*;
+
+/// Reading length-prefixed data into `heapless` containers.
+#[cfg(feature = "heapless")]
+mod heapless_io;
+#[cfg(feature = "heapless")]
+pub use heapless_io::*;
+
 /// The shorthand types (e.g u64be, f32le, etc)
 mod shorthand_types;
 pub use shorthand_types::*;
+
+/// `FixedBytes<N>`, for opaque byte fields that have no byte order.
+mod fixed_bytes;
+pub use fixed_bytes::*;
+
+/// `CStrField<N>`, for NUL-terminated ASCII or Latin-1 text in a fixed-width field.
+mod cstr_field;
+pub use cstr_field::*;
+
+/// `PackedBits`, for arrays of flags stored one bit each.
+mod packed_bits;
+pub use packed_bits::*;
+
+/// `Guid`, with the mixed-endian layout Microsoft formats use.
+#[cfg(all(feature = "little_endian", feature = "integer_impls"))]
+mod guid;
+#[cfg(all(feature = "little_endian", feature = "integer_impls"))]
+pub use guid::*;
+
+/// Re-exports of the commonly used types, traits and functions, for `use simple_endian::prelude::*;`.
+pub mod prelude;
+
+/// Every compound assignment operator, on both wrappers, checked against the same operation in native endian.
+#[cfg(all(
+    test,
+    feature = "both_endian",
+    feature = "math_ops",
+    feature = "bitwise",
+    feature = "shift_ops",
+    feature = "float_impls"
+))]
+mod assign_ops_tests {
+    use super::*;
+
+    macro_rules! assert_assign_op {
+        ($wrapper:ident<$ty:ty>, $a:expr, $op:tt, $b:expr) => {{
+            let mut wrapped = $wrapper::<$ty>::from($a);
+            wrapped $op $wrapper::<$ty>::from($b);
+            let mut native: $ty = $a;
+            native $op $b;
+            assert_eq!(
+                wrapped.to_native(),
+                native,
+                concat!(stringify!($wrapper<$ty>), " ", stringify!($op))
+            );
+        }};
+    }
+
+    macro_rules! assert_integer_assign_ops {
+        ($wrapper:ident<$ty:ty>, $a:expr, $b:expr) => {
+            assert_assign_op!($wrapper<$ty>, $a, +=, $b);
+            assert_assign_op!($wrapper<$ty>, $a, -=, $b);
+            assert_assign_op!($wrapper<$ty>, $a, *=, $b);
+            assert_assign_op!($wrapper<$ty>, $a, /=, $b);
+            assert_assign_op!($wrapper<$ty>, $a, %=, $b);
+            assert_assign_op!($wrapper<$ty>, $a, &=, $b);
+            assert_assign_op!($wrapper<$ty>, $a, |=, $b);
+            assert_assign_op!($wrapper<$ty>, $a, ^=, $b);
+            assert_assign_op!($wrapper<$ty>, $a, <<=, 3);
+            assert_assign_op!($wrapper<$ty>, $a, >>=, 3);
+        };
+    }
+
+    macro_rules! assert_float_assign_ops {
+        ($wrapper:ident<$ty:ty>, $a:expr, $b:expr) => {
+            assert_assign_op!($wrapper<$ty>, $a, +=, $b);
+            assert_assign_op!($wrapper<$ty>, $a, -=, $b);
+            assert_assign_op!($wrapper<$ty>, $a, *=, $b);
+            assert_assign_op!($wrapper<$ty>, $a, /=, $b);
+            assert_assign_op!($wrapper<$ty>, $a, %=, $b);
+        };
+    }
+
+    #[test]
+    fn unsigned() {
+        assert_integer_assign_ops!(BigEndian<u32>, 0x0012_3456, 0x00f0);
+        assert_integer_assign_ops!(LittleEndian<u32>, 0x0012_3456, 0x00f0);
+    }
+
+    #[test]
+    fn signed() {
+        assert_integer_assign_ops!(BigEndian<i16>, -1234, 17);
+        assert_integer_assign_ops!(LittleEndian<i16>, -1234, 17);
+    }
+
+    #[test]
+    fn float() {
+        assert_float_assign_ops!(BigEndian<f64>, -1234.5678, 0.75);
+        assert_float_assign_ops!(LittleEndian<f64>, -1234.5678, 0.75);
+    }
+
+    #[test]
+    fn shift_by_native() {
+        let mut be = BigEndian::<u32>::from(0x0123);
+        be.shl_assign_native(8);
+        be.shr_assign_native(4);
+        assert_eq!(be.to_native(), 0x1230);
+        let mut le = LittleEndian::<i16>::from(-64);
+        le.shr_assign_native(2);
+        le.shl_assign_native(1);
+        assert_eq!(le.to_native(), -32);
+    }
+}
@@ -1,6 +1,6 @@
 //! The math operations.  These all have some cost because they require conversion to native endian.
 #[allow(unused_imports)]
-use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
 
 #[allow(unused_imports)]
 use super::*;
@@ -62,6 +62,20 @@ macro_rules! add_math_ops {
                 *self = *self - other;
             }
         }
+
+        impl Rem for $wrap_ty {
+            type Output = Self;
+
+            fn rem(self, other: Self) -> Self {
+                Self::from(self.to_native() % other.to_native())
+            }
+        }
+
+        impl RemAssign for $wrap_ty {
+            fn rem_assign(&mut self, other: Self) {
+                *self = *self % other;
+            }
+        }
     };
 }
 
@@ -131,6 +145,253 @@ mod le {
     }
 }
 
+/// Aggregate helpers for slices of endian values.  Each element is converted to native endian exactly once, and the
+/// arithmetic is done entirely in native endian, rather than swapping back and forth on every operation.
+mod aggregates {
+    use core::cmp::Ordering;
+    use core::iter::Sum;
+    use core::ops::Div;
+
+    /// Types that a mean can be taken of.  Implemented for the floats.
+    pub trait Mean: Sum + Div<Output = Self> {
+        /// Converts the number of elements averaged into `Self`.
+        fn from_len(len: usize) -> Self;
+    }
+
+    impl Mean for f32 {
+        fn from_len(len: usize) -> Self {
+            len as f32
+        }
+    }
+
+    impl Mean for f64 {
+        fn from_len(len: usize) -> Self {
+            len as f64
+        }
+    }
+
+    /// Sums a slice of `BigEndian<T>` or `LittleEndian<T>` values, returning the native result.
+    ///
+    /// ```rust
+    /// use simple_endian::*;
+    /// let samples: [f32be; 3] = [1.0.into(), 2.0.into(), 3.5.into()];
+    /// let total: f32 = sum_native(&samples);
+    /// assert_eq!(total, 6.5);
+    /// ```
+    pub fn sum_native<E, T>(values: &[E]) -> T
+    where
+        E: Copy + Into<T>,
+        T: Sum,
+    {
+        values.iter().map(|v| (*v).into()).sum()
+    }
+
+    /// Takes the mean of a slice of endian floats, returning the native result, or `None` if the slice is empty.
+    pub fn mean_native<E, T>(values: &[E]) -> Option<T>
+    where
+        E: Copy + Into<T>,
+        T: Mean,
+    {
+        if values.is_empty() {
+            None
+        } else {
+            Some(sum_native::<E, T>(values) / T::from_len(values.len()))
+        }
+    }
+
+    /// Returns the smallest native value in the slice, or `None` if it is empty.  Uses `partial_cmp`, so it works on
+    /// the floats; values that don't compare (NaN) are skipped unless they come first.
+    pub fn min_native<E, T>(values: &[E]) -> Option<T>
+    where
+        E: Copy + Into<T>,
+        T: PartialOrd,
+    {
+        values
+            .iter()
+            .map(|v| (*v).into())
+            .reduce(|acc, v| match v.partial_cmp(&acc) {
+                Some(Ordering::Less) => v,
+                _ => acc,
+            })
+    }
+
+    /// Returns the largest native value in the slice, or `None` if it is empty.  Uses `partial_cmp`, so it works on
+    /// the floats; values that don't compare (NaN) are skipped unless they come first.
+    pub fn max_native<E, T>(values: &[E]) -> Option<T>
+    where
+        E: Copy + Into<T>,
+        T: PartialOrd,
+    {
+        values
+            .iter()
+            .map(|v| (*v).into())
+            .reduce(|acc, v| match v.partial_cmp(&acc) {
+                Some(Ordering::Greater) => v,
+                _ => acc,
+            })
+    }
+}
+pub use aggregates::*;
+
+/// Conversions to and from `core::num::Wrapping`, so that modular arithmetic can be done with `Wrapping` and the result
+/// stored back in a specific endian.
+mod wrapping {
+    use super::*;
+    use core::num::Wrapping;
+
+    /// Stores a `Wrapping` result as big endian.
+    ///
+    /// There are deliberately no arithmetic operators between `Wrapping<T>` and the endian types: a second right-hand
+    /// side type would break inference for `x + y.into()`.  Convert to `Wrapping`, do the arithmetic there, and convert
+    /// back.
+    impl<T: SpecificEndian<T>> From<Wrapping<T>> for BigEndian<T> {
+        fn from(v: Wrapping<T>) -> Self {
+            Self::from(v.0)
+        }
+    }
+
+    impl<T: SpecificEndian<T>> From<BigEndian<T>> for Wrapping<T> {
+        fn from(v: BigEndian<T>) -> Self {
+            Wrapping(v.to_native())
+        }
+    }
+
+    /// Stores a `Wrapping` result as little endian.  As with big endian, there are no operators mixing the two types.
+    impl<T: SpecificEndian<T>> From<Wrapping<T>> for LittleEndian<T> {
+        fn from(v: Wrapping<T>) -> Self {
+            Self::from(v.0)
+        }
+    }
+
+    impl<T: SpecificEndian<T>> From<LittleEndian<T>> for Wrapping<T> {
+        fn from(v: LittleEndian<T>) -> Self {
+            Wrapping(v.to_native())
+        }
+    }
+}
+
+/// Conversions to and from `core::num::Saturating`, the saturating counterpart of the `Wrapping` conversions above.  A
+/// `Saturating` field is read and written through the specific-endian type it converts to.
+mod saturating {
+    use super::*;
+    use core::num::Saturating;
+
+    impl<T: SpecificEndian<T>> From<Saturating<T>> for BigEndian<T> {
+        fn from(v: Saturating<T>) -> Self {
+            Self::from(v.0)
+        }
+    }
+
+    impl<T: SpecificEndian<T>> From<BigEndian<T>> for Saturating<T> {
+        fn from(v: BigEndian<T>) -> Self {
+            Saturating(v.to_native())
+        }
+    }
+
+    impl<T: SpecificEndian<T>> From<Saturating<T>> for LittleEndian<T> {
+        fn from(v: Saturating<T>) -> Self {
+            Self::from(v.0)
+        }
+    }
+
+    impl<T: SpecificEndian<T>> From<LittleEndian<T>> for Saturating<T> {
+        fn from(v: LittleEndian<T>) -> Self {
+            Saturating(v.to_native())
+        }
+    }
+}
+
+/// Adding values of different byte orders.
+mod cross {
+    use super::*;
+
+    impl<T: SpecificEndian<T> + Add<Output = T>> BigEndian<T> {
+        /// Adds a little-endian value, giving a big-endian result.
+        ///
+        /// There is deliberately no `+` between `BigEndian<T>` and `LittleEndian<T>`: the byte order of the result
+        /// would be arbitrary, and mixing them is more often a bug than intended.  This makes the rare intentional case
+        /// explicit, with the result in the same order as `self`.
+        pub fn add_cross(self, other: LittleEndian<T>) -> BigEndian<T> {
+            Self::from(self.to_native() + other.to_native())
+        }
+    }
+
+    impl<T: SpecificEndian<T> + Add<Output = T>> LittleEndian<T> {
+        /// Adds a big-endian value, giving a little-endian result.
+        ///
+        /// There is deliberately no `+` between `BigEndian<T>` and `LittleEndian<T>`: the byte order of the result
+        /// would be arbitrary, and mixing them is more often a bug than intended.  This makes the rare intentional case
+        /// explicit, with the result in the same order as `self`.
+        pub fn add_cross(self, other: BigEndian<T>) -> LittleEndian<T> {
+            Self::from(self.to_native() + other.to_native())
+        }
+    }
+}
+
+/// Storing a float as a rounded integer, as formats with scaled integer fields (audio samples, fixed-point
+/// coordinates) do.  The rounding is done by hand because `f64::round()` and friends aren't available in `core`.
+mod rounding {
+    #[allow(unused_imports)]
+    use super::*;
+
+    /// How `from_float_rounded` rounds a value that isn't a whole number.
+    #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+    pub enum RoundMode {
+        /// To the nearest integer, with halfway cases rounded away from zero, like `f64::round()`.
+        Nearest,
+        /// Towards negative infinity.
+        Floor,
+        /// Towards positive infinity.
+        Ceil,
+        /// Towards zero.
+        Trunc,
+    }
+
+    #[allow(unused_macros)]
+    macro_rules! add_float_rounded {
+        ($($wrapper:ident)+, $native_ty:ty) => {
+            $(
+                impl $wrapper<$native_ty> {
+                    /// Rounds `v` according to `mode` and stores it.  Like an `as` cast, values outside the range
+                    /// of the integer saturate, and NaN is stored as 0.
+                    pub fn from_float_rounded(v: f64, mode: RoundMode) -> Self {
+                        let truncated = v as $native_ty;
+                        let fraction = v - truncated as f64;
+                        let rounded = match mode {
+                            RoundMode::Trunc => truncated,
+                            RoundMode::Floor if fraction < 0.0 => truncated.saturating_sub(1),
+                            RoundMode::Ceil if fraction > 0.0 => truncated.saturating_add(1),
+                            RoundMode::Nearest if fraction >= 0.5 => truncated.saturating_add(1),
+                            RoundMode::Nearest if fraction <= -0.5 => truncated.saturating_sub(1),
+                            _ => truncated,
+                        };
+                        Self::from(rounded)
+                    }
+                }
+            )+
+        };
+    }
+
+    #[cfg(feature = "byte_impls")]
+    mod bytes {
+        use super::*;
+        add_float_rounded!(BigEndian LittleEndian, u8);
+        add_float_rounded!(BigEndian LittleEndian, i8);
+    }
+
+    #[cfg(feature = "integer_impls")]
+    mod integers {
+        use super::*;
+        add_float_rounded!(BigEndian LittleEndian, u16);
+        add_float_rounded!(BigEndian LittleEndian, i16);
+        add_float_rounded!(BigEndian LittleEndian, u32);
+        add_float_rounded!(BigEndian LittleEndian, i32);
+        add_float_rounded!(BigEndian LittleEndian, u64);
+        add_float_rounded!(BigEndian LittleEndian, i64);
+    }
+}
+pub use rounding::*;
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -169,4 +430,135 @@ mod tests {
         ne1 /= 10.0;
         assert_eq!(ne1, be1.into());
     }
+
+    #[test]
+    fn rem_be() {
+        let be1 = BigEndian::<u32>::from(10) % 3u32.into();
+        assert_eq!(be1, 1.into());
+    }
+
+    #[test]
+    fn rem_assign_le() {
+        let mut le1 = LittleEndian::<i64>::from(-17);
+        le1 %= 5.into();
+        assert_eq!(le1.to_native(), -2);
+    }
+
+    #[test]
+    fn rem_fp_le() {
+        let mut le1 = f32le::from(7.5);
+        le1 %= 2.0.into();
+        assert_eq!(le1.to_native(), 1.5);
+    }
+
+    #[test]
+    fn aggregates_fp_le() {
+        let samples: [f64le; 4] = [1.5.into(), (-2.0).into(), 4.0.into(), 0.5.into()];
+        assert_eq!(sum_native::<_, f64>(&samples), 4.0);
+        assert_eq!(mean_native::<_, f64>(&samples), Some(1.0));
+        assert_eq!(min_native::<_, f64>(&samples), Some(-2.0));
+        assert_eq!(max_native::<_, f64>(&samples), Some(4.0));
+    }
+
+    #[test]
+    fn aggregates_empty() {
+        let samples: [f32be; 0] = [];
+        assert_eq!(sum_native::<_, f32>(&samples), 0.0);
+        assert_eq!(mean_native::<_, f32>(&samples), None);
+        assert_eq!(min_native::<_, f32>(&samples), None);
+        assert_eq!(max_native::<_, f32>(&samples), None);
+    }
+
+    #[test]
+    fn aggregates_integer_be() {
+        let values: [u32be; 3] = [7.into(), 3.into(), 9.into()];
+        assert_eq!(sum_native::<_, u32>(&values), 19);
+        assert_eq!(min_native::<_, u32>(&values), Some(3));
+        assert_eq!(max_native::<_, u32>(&values), Some(9));
+    }
+
+    #[test]
+    fn float_rounded_halfway() {
+        let round =
+            |v: f64, mode: RoundMode| BigEndian::<i32>::from_float_rounded(v, mode).to_native();
+        assert_eq!(round(2.5, RoundMode::Nearest), 3);
+        assert_eq!(round(-2.5, RoundMode::Nearest), -3);
+        assert_eq!(round(2.5, RoundMode::Floor), 2);
+        assert_eq!(round(-2.5, RoundMode::Floor), -3);
+        assert_eq!(round(2.5, RoundMode::Ceil), 3);
+        assert_eq!(round(-2.5, RoundMode::Ceil), -2);
+        assert_eq!(round(2.5, RoundMode::Trunc), 2);
+        assert_eq!(round(-2.5, RoundMode::Trunc), -2);
+        assert_eq!(round(2.4999, RoundMode::Nearest), 2);
+        assert_eq!(round(-7.0, RoundMode::Floor), -7);
+    }
+
+    #[test]
+    fn float_rounded_saturates() {
+        let le = LittleEndian::<u16>::from_float_rounded(70000.0, RoundMode::Nearest);
+        assert_eq!(le.to_native(), u16::MAX);
+        let le = LittleEndian::<u16>::from_float_rounded(-0.7, RoundMode::Floor);
+        assert_eq!(le.to_native(), 0);
+        let be = BigEndian::<i8>::from_float_rounded(f64::NAN, RoundMode::Ceil);
+        assert_eq!(be.to_native(), 0);
+        let be = BigEndian::<i64>::from_float_rounded(-1e300, RoundMode::Floor);
+        assert_eq!(be.to_native(), i64::MIN);
+    }
+
+    #[test]
+    fn add_cross_endian() {
+        let be = BigEndian::<u32>::from(0x1000);
+        let le = LittleEndian::<u32>::from(0x0234);
+        let sum: BigEndian<u32> = be.add_cross(le);
+        assert_eq!(sum.to_native(), 0x1234);
+        let sum: LittleEndian<u32> = le.add_cross(be);
+        assert_eq!(sum.to_native(), 0x1234);
+        assert_eq!(
+            f64le::from(0.5).add_cross(f64be::from(1.0)).to_native(),
+            1.5
+        );
+    }
+
+    #[test]
+    fn wrapping_add_u8be() {
+        use core::num::Wrapping;
+        let be1 = BigEndian::<u8>::from(250);
+        let sum = Wrapping::from(be1) + Wrapping(10);
+        let be2 = BigEndian::<u8>::from(sum);
+        assert_eq!(be2.to_native(), 4);
+    }
+
+    #[test]
+    fn wrapping_mul_u32le() {
+        use core::num::Wrapping;
+        let mut le1 = LittleEndian::<u32>::from(0x9e3779b9);
+        let mut w = Wrapping::from(le1);
+        w *= Wrapping(0x01000193);
+        le1 = w.into();
+        assert_eq!(le1.to_native(), 0x9e3779b9u32.wrapping_mul(0x01000193));
+    }
+
+    #[test]
+    fn saturating_add_u16le() {
+        use core::num::Saturating;
+        let sum = Saturating::from(u16le::from(65000)) + Saturating(1000);
+        assert_eq!(u16le::from(sum).to_native(), u16::MAX);
+    }
+
+    #[cfg(feature = "io-std")]
+    #[test]
+    fn roundtrip_saturating_u16le() {
+        use core::num::Saturating;
+        let level: Saturating<u16le> = Saturating(0x1234.into());
+        assert_eq!(<Saturating<u16le>>::WIRE_SIZE, 2);
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &level).unwrap();
+        assert_eq!(buf, [0x34, 0x12]);
+        let read: Saturating<u16le> = read_specific(&mut &buf[..]).unwrap();
+        assert_eq!(read, level);
+
+        // Arithmetic is done on the native value, then stored back.
+        let sum = Saturating::from(read.0) + Saturating(u16::MAX);
+        assert_eq!(u16le::from(sum).to_native(), u16::MAX);
+    }
 }
@@ -0,0 +1,175 @@
+/*!
+`PackedBits`, an array of booleans stored one bit each, such as an allocation bitmap.  Bits are numbered most significant
+first within each byte, so bit 0 is the high bit of the first byte.
+
+Stable Rust can't compute an array length from a const parameter yet, so the byte count is given as a second parameter
+and checked at compile time:
+
+```rust
+use simple_endian::*;
+
+let mut free: PackedBits<13, 2> = PackedBits::new();
+free.set(0, true);
+free.set(12, true);
+assert_eq!(free.to_bytes(), [0x80, 0x08]);
+assert_eq!(free.get(12), Some(true));
+assert_eq!(free.get(13), None);
+```
+
+A mismatched byte count fails to build:
+
+```compile_fail
+use simple_endian::*;
+
+let bits: PackedBits<13, 1> = PackedBits::new();
+```
+*/
+
+/// `BITS` booleans packed into `BYTES` bytes, where `BYTES` must be `BITS` divided by 8, rounded up.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[repr(transparent)]
+pub struct PackedBits<const BITS: usize, const BYTES: usize>([u8; BYTES]);
+
+impl<const BITS: usize, const BYTES: usize> PackedBits<BITS, BYTES> {
+    const SIZE_CHECK: () = assert!(
+        BYTES == BITS.div_ceil(8),
+        "PackedBits<BITS, BYTES> needs BYTES to be BITS / 8 rounded up"
+    );
+
+    /// Creates a bitset with every bit cleared.
+    pub fn new() -> Self {
+        Self::from_bytes([0; BYTES])
+    }
+
+    /// Wraps bytes that are already packed.  Any unused bits in the last byte are kept as they are.
+    pub fn from_bytes(bytes: [u8; BYTES]) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::SIZE_CHECK;
+        Self(bytes)
+    }
+
+    /// Returns the packed bytes.
+    pub fn to_bytes(&self) -> [u8; BYTES] {
+        self.0
+    }
+
+    /// The number of bits.
+    pub fn len(&self) -> usize {
+        BITS
+    }
+
+    /// Whether there are no bits at all.
+    pub fn is_empty(&self) -> bool {
+        BITS == 0
+    }
+
+    /// Returns bit `i`, or `None` if `i` is out of range.
+    pub fn get(&self, i: usize) -> Option<bool> {
+        if i < BITS {
+            Some(self.0[i / 8] & (0x80 >> (i % 8)) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Sets bit `i` to `value`.  Panics if `i` is out of range.
+    pub fn set(&mut self, i: usize, value: bool) {
+        assert!(
+            i < BITS,
+            "bit index {} out of range for PackedBits<{}>",
+            i,
+            BITS
+        );
+        let mask = 0x80 >> (i % 8);
+        if value {
+            self.0[i / 8] |= mask;
+        } else {
+            self.0[i / 8] &= !mask;
+        }
+    }
+
+    /// Iterates over the bits in order.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..BITS).map(move |i| self.0[i / 8] & (0x80 >> (i % 8)) != 0)
+    }
+}
+
+impl<const BITS: usize, const BYTES: usize> Default for PackedBits<BITS, BYTES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "io-std")]
+mod io {
+    use super::*;
+    use crate::{EndianRead, EndianWrite, WireSize};
+    use std::io::{self, Read, Write};
+
+    impl<const BITS: usize, const BYTES: usize> EndianRead for PackedBits<BITS, BYTES> {
+        fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+            let mut buf = [0u8; BYTES];
+            reader.read_exact(&mut buf)?;
+            Ok(Self::from_bytes(buf))
+        }
+    }
+
+    impl<const BITS: usize, const BYTES: usize> EndianWrite for PackedBits<BITS, BYTES> {
+        fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+            writer.write_all(&self.0)
+        }
+    }
+
+    impl<const BITS: usize, const BYTES: usize> WireSize for PackedBits<BITS, BYTES> {
+        const WIRE_SIZE: usize = BYTES;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn unaligned_bits() {
+        let mut bits = PackedBits::<13, 2>::new();
+        assert_eq!(bits.len(), 13);
+        for i in [0, 7, 8, 12].iter() {
+            bits.set(*i, true);
+        }
+        assert_eq!(bits.to_bytes(), [0x81, 0x88]);
+        assert_eq!(
+            bits.iter().collect::<Vec<_>>(),
+            [
+                true, false, false, false, false, false, false, true, true, false, false, false,
+                true
+            ]
+        );
+        bits.set(7, false);
+        assert_eq!(bits.get(7), Some(false));
+        assert_eq!(bits.get(13), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_out_of_range() {
+        PackedBits::<13, 2>::new().set(13, true);
+    }
+
+    #[test]
+    fn unused_bits_preserved() {
+        let bits = PackedBits::<13, 2>::from_bytes([0x00, 0x07]);
+        assert!(bits.iter().all(|b| !b));
+        assert_eq!(bits.to_bytes(), [0x00, 0x07]);
+    }
+
+    #[cfg(feature = "io-std")]
+    #[test]
+    fn roundtrip_io() {
+        let bits: PackedBits<13, 2> = read_specific(&mut &[0xa5u8, 0xf0][..]).unwrap();
+        assert_eq!(bits.get(0), Some(true));
+        assert_eq!(bits.get(1), Some(false));
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &bits).unwrap();
+        assert_eq!(buf, [0xa5, 0xf0]);
+    }
+}
@@ -0,0 +1,76 @@
+/*!
+`nom` parsers for the endian types.  `parse_specific` parses any type with a fixed wire size, so the same function
+covers the primitive wrappers and composite types like `Guid` or `WireTimestamp<E>`.
+
+```rust
+use nom::{error::Error, IResult, Parser};
+use simple_endian::*;
+
+fn header(input: &[u8]) -> IResult<&[u8], (u32be, u16le), Error<&[u8]>> {
+    (parse_specific, parse_specific).parse(input)
+}
+
+let (rest, (magic, version)) = header(&[0xca, 0xfe, 0xf0, 0x0d, 0x02, 0x00, 0xff]).unwrap();
+assert_eq!(magic.to_native(), 0xcafef00d);
+assert_eq!(version.to_native(), 2);
+assert_eq!(rest, [0xff]);
+```
+*/
+use nom::error::{ErrorKind, ParseError};
+use nom::{Err, IResult};
+
+use super::*;
+
+/// Parses an `E` from the start of `input`.  Like nom's own complete-input parsers, this fails with `ErrorKind::Eof`
+/// if `input` is too short, and with `ErrorKind::Verify` if the bytes aren't a valid `E`.
+pub fn parse_specific<'a, E, Error>(input: &'a [u8]) -> IResult<&'a [u8], E, Error>
+where
+    E: EndianRead + WireSize,
+    Error: ParseError<&'a [u8]>,
+{
+    if input.len() < E::WIRE_SIZE {
+        return Err(Err::Error(Error::from_error_kind(input, ErrorKind::Eof)));
+    }
+    let (mut value, rest) = input.split_at(E::WIRE_SIZE);
+    match E::read_from(&mut value) {
+        Ok(parsed) => Ok((rest, parsed)),
+        Err(_) => Err(Err::Error(Error::from_error_kind(input, ErrorKind::Verify))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use nom::error::{Error, ErrorKind};
+    use nom::multi::count;
+    use nom::{Err, IResult, Parser};
+
+    fn table(input: &[u8]) -> IResult<&[u8], Vec<u32le>, Error<&[u8]>> {
+        let (input, n) = parse_specific::<u16be, _>(input)?;
+        count(parse_specific, n.to_native() as usize).parse(input)
+    }
+
+    #[test]
+    fn parse_counted_table() {
+        let data = [
+            0x00, 0x02, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0xaa,
+        ];
+        let (rest, entries) = table(&data).unwrap();
+        assert_eq!(entries, [1.into(), 2.into()]);
+        assert_eq!(rest, [0xaa]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let short = parse_specific::<u64be, Error<&[u8]>>(&[1, 2, 3]);
+        assert_eq!(
+            short,
+            Err(Err::Error(Error::new(&[1u8, 2, 3][..], ErrorKind::Eof)))
+        );
+        let invalid = parse_specific::<BigEndian<bool>, Error<&[u8]>>(&[7]);
+        assert_eq!(
+            invalid,
+            Err(Err::Error(Error::new(&[7u8][..], ErrorKind::Verify)))
+        );
+    }
+}
@@ -0,0 +1,30 @@
+/*!
+The commonly used parts of the crate, for glob importing.
+
+```rust
+use simple_endian::prelude::*;
+
+#[repr(C)]
+struct Header {
+    magic: u32be,
+    length: u16le,
+}
+
+let header = Header { magic: 0xfeedface.into(), length: 12.into() };
+assert_eq!(header.magic.to_native(), 0xfeedface);
+assert_eq!(u16::from(header.length), 12);
+```
+*/
+
+pub use crate::fixed_bytes::FixedBytes;
+pub use crate::shorthand_types::*;
+pub use crate::specific_endian::{BigEndian, LittleEndian, SpecificEndian};
+
+#[cfg(feature = "native_endian")]
+pub use crate::specific_endian::NativeEndian;
+
+#[cfg(feature = "io-std")]
+pub use crate::io::{read_specific, write_specific, Endian, EndianRead, EndianWrite, WireSize};
+
+#[cfg(all(feature = "io-std", feature = "big_endian", feature = "little_endian"))]
+pub use crate::io::{read_with_order, write_with_order};
@@ -33,6 +33,30 @@ macro_rules! add_shift_ops {
                 *self = Self::from((*self).to_native() >> rhs.to_native());
             }
         }
+
+        impl $wrap_ty {
+            /// Shifts left by a native `u32` amount, so `x.shl_native(4)` works without wrapping the 4.  This is a
+            /// method rather than `Shl<u32>`, because a second right-hand side type would break inference for
+            /// `x << y.into()`.  As with the native types, shifting by the bit width or more overflows.
+            pub fn shl_native(self, n: u32) -> Self {
+                Self::from(self.to_native() << n)
+            }
+
+            /// Shifts right by a native `u32` amount, the counterpart of `shl_native`.
+            pub fn shr_native(self, n: u32) -> Self {
+                Self::from(self.to_native() >> n)
+            }
+
+            /// Shifts `self` left in place by a native `u32` amount, the method form of `x <<= 4`.
+            pub fn shl_assign_native(&mut self, n: u32) {
+                *self = self.shl_native(n);
+            }
+
+            /// Shifts `self` right in place by a native `u32` amount, the method form of `x >>= 4`.
+            pub fn shr_assign_native(&mut self, n: u32) {
+                *self = self.shr_native(n);
+            }
+        }
     };
 }
 
@@ -62,7 +86,7 @@ mod be {
     }
 }
 
-#[cfg(feature = "big_endian")]
+#[cfg(feature = "little_endian")]
 mod le {
     use super::*;
     #[cfg(feature = "byte_impls")]
@@ -113,4 +137,49 @@ mod tests {
         ne1 >>= 5;
         assert_eq!(ne1, be1.into());
     }
+
+    #[test]
+    fn shl_native_rhs() {
+        let be1 = BigEndian::<u16>::from(5).shl_native(12);
+        assert_eq!(be1.to_native(), 5u16 << 12);
+        let mut le1 = LittleEndian::<u32>::from(0xfee1);
+        le1.shl_assign_native(4);
+        assert_eq!(le1.to_native(), 0xfee10);
+        // The wrapped right-hand side still infers.
+        let be2 = BigEndian::<u16>::from(5) << 12.into();
+        assert_eq!(be2, be1);
+    }
+
+    #[test]
+    fn shr_native_rhs() {
+        let be1 = BigEndian::<i64>::from(-256).shr_native(4);
+        assert_eq!(be1.to_native(), -16);
+        let mut le1 = LittleEndian::<u8>::from(0xf0);
+        le1.shr_assign_native(4);
+        assert_eq!(le1.to_native(), 0x0f);
+    }
+
+    #[test]
+    fn shift_bit_width_boundary() {
+        assert_eq!(BigEndian::<u16>::from(1).shl_native(15).to_native(), 0x8000);
+        assert_eq!(
+            LittleEndian::<u16>::from(0x8000).shr_native(15).to_native(),
+            1
+        );
+        assert_eq!(
+            BigEndian::<u128>::from(1).shl_native(127).to_native(),
+            1u128 << 127
+        );
+        assert_eq!(
+            LittleEndian::<i8>::from(i8::MIN).shr_native(7).to_native(),
+            -1
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn shift_overflow() {
+        let _ = BigEndian::<u16>::from(1).shl_native(16);
+    }
 }
@@ -0,0 +1,244 @@
+/*!
+Parsing the primitive wrappers from the start of a byte slice, without `std`.  This is the `no_std` counterpart of
+`parse_prefix`: each value is split off the front of the slice and decoded with `from_be_bytes`, `from_le_bytes` or
+`from_ne_bytes`, and the rest of the slice is returned for the next field.
+
+```rust
+use simple_endian::*;
+
+let data = [0xca, 0xfe, 0xf0, 0x0d, 0x02, 0x00, 0xff];
+let (magic, rest) = u32be::split_prefix(&data).unwrap();
+let (version, rest) = u16le::split_prefix(rest).unwrap();
+assert_eq!(magic.to_native(), 0xcafef00d);
+assert_eq!(version.to_native(), 2);
+assert_eq!(rest, [0xff]);
+```
+*/
+#[allow(unused_imports)]
+use super::*;
+
+/// A type that can be parsed from the start of a byte slice without `std`.
+pub trait SplitPrefix: Sized {
+    /// Parses a `Self` from the start of `data`, returning it along with the rest of `data`.  Fails if `data` is too
+    /// short, or if the bytes aren't a valid `Self`.
+    fn split_prefix(data: &[u8]) -> Result<(Self, &[u8]), &'static str>;
+}
+
+/// An unsigned integer type used as a length or count prefix.
+pub trait LengthPrefix: Sized {
+    /// Builds a prefix holding `len`, or returns `None` if `len` doesn't fit.
+    fn from_len(len: usize) -> Option<Self>;
+    /// The length held by the prefix, or `None` if it doesn't fit in a `usize`.
+    fn to_len(&self) -> Option<usize>;
+}
+
+#[allow(unused_macros)]
+macro_rules! add_split_prefix {
+    ($wrapper:ident, $native_ty:ty, $from_bytes:ident) => {
+        impl SplitPrefix for $wrapper<$native_ty> {
+            fn split_prefix(data: &[u8]) -> Result<(Self, &[u8]), &'static str> {
+                const N: usize = core::mem::size_of::<$native_ty>();
+                let (bytes, rest) = data
+                    .split_first_chunk::<N>()
+                    .ok_or("not enough data for the value")?;
+                Ok((Self::from(<$native_ty>::$from_bytes(*bytes)), rest))
+            }
+        }
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! add_bool_split_prefix {
+    ($wrapper:ident) => {
+        impl SplitPrefix for $wrapper<bool> {
+            fn split_prefix(data: &[u8]) -> Result<(Self, &[u8]), &'static str> {
+                match data.split_first() {
+                    Some((0, rest)) => Ok((Self::from(false), rest)),
+                    Some((1, rest)) => Ok((Self::from(true), rest)),
+                    Some(_) => Err("data is not a valid value"),
+                    None => Err("not enough data for the value"),
+                }
+            }
+        }
+    };
+}
+
+/// Implements `LengthPrefix` for a wrapper over an unsigned integer.
+#[allow(unused_macros)]
+macro_rules! add_length_prefix {
+    ($wrapper:ident, $native_ty:ty) => {
+        impl LengthPrefix for $wrapper<$native_ty> {
+            fn from_len(len: usize) -> Option<Self> {
+                <$native_ty>::try_from(len).ok().map(Self::from)
+            }
+
+            fn to_len(&self) -> Option<usize> {
+                usize::try_from(self.to_native()).ok()
+            }
+        }
+    };
+}
+
+/// Implements both `SplitPrefix` and `LengthPrefix`.
+#[allow(unused_macros)]
+macro_rules! add_split_length_prefix {
+    ($wrapper:ident, $native_ty:ty, $from_bytes:ident) => {
+        add_split_prefix!($wrapper, $native_ty, $from_bytes);
+        add_length_prefix!($wrapper, $native_ty);
+    };
+}
+
+#[cfg(feature = "big_endian")]
+mod be {
+    #[allow(unused_imports)]
+    use super::*;
+    #[cfg(feature = "byte_impls")]
+    mod bytes {
+        use super::*;
+        add_bool_split_prefix!(BigEndian);
+        add_split_length_prefix!(BigEndian, u8, from_be_bytes);
+        add_split_prefix!(BigEndian, i8, from_be_bytes);
+    }
+
+    #[cfg(feature = "integer_impls")]
+    mod integers {
+        use super::*;
+        add_split_length_prefix!(BigEndian, u16, from_be_bytes);
+        add_split_prefix!(BigEndian, i16, from_be_bytes);
+        add_split_length_prefix!(BigEndian, u32, from_be_bytes);
+        add_split_prefix!(BigEndian, i32, from_be_bytes);
+        add_split_length_prefix!(BigEndian, u64, from_be_bytes);
+        add_split_prefix!(BigEndian, i64, from_be_bytes);
+        add_split_prefix!(BigEndian, u128, from_be_bytes);
+        add_split_prefix!(BigEndian, i128, from_be_bytes);
+        add_split_prefix!(BigEndian, usize, from_be_bytes);
+        add_split_prefix!(BigEndian, isize, from_be_bytes);
+    }
+
+    #[cfg(feature = "float_impls")]
+    mod floats {
+        use super::*;
+        add_split_prefix!(BigEndian, f32, from_be_bytes);
+        add_split_prefix!(BigEndian, f64, from_be_bytes);
+    }
+}
+
+#[cfg(feature = "little_endian")]
+mod le {
+    #[allow(unused_imports)]
+    use super::*;
+    #[cfg(feature = "byte_impls")]
+    mod bytes {
+        use super::*;
+        add_bool_split_prefix!(LittleEndian);
+        add_split_length_prefix!(LittleEndian, u8, from_le_bytes);
+        add_split_prefix!(LittleEndian, i8, from_le_bytes);
+    }
+
+    #[cfg(feature = "integer_impls")]
+    mod integers {
+        use super::*;
+        add_split_length_prefix!(LittleEndian, u16, from_le_bytes);
+        add_split_prefix!(LittleEndian, i16, from_le_bytes);
+        add_split_length_prefix!(LittleEndian, u32, from_le_bytes);
+        add_split_prefix!(LittleEndian, i32, from_le_bytes);
+        add_split_length_prefix!(LittleEndian, u64, from_le_bytes);
+        add_split_prefix!(LittleEndian, i64, from_le_bytes);
+        add_split_prefix!(LittleEndian, u128, from_le_bytes);
+        add_split_prefix!(LittleEndian, i128, from_le_bytes);
+        add_split_prefix!(LittleEndian, usize, from_le_bytes);
+        add_split_prefix!(LittleEndian, isize, from_le_bytes);
+    }
+
+    #[cfg(feature = "float_impls")]
+    mod floats {
+        use super::*;
+        add_split_prefix!(LittleEndian, f32, from_le_bytes);
+        add_split_prefix!(LittleEndian, f64, from_le_bytes);
+    }
+}
+
+#[cfg(feature = "native_endian")]
+mod ne {
+    #[allow(unused_imports)]
+    use super::*;
+    #[cfg(feature = "byte_impls")]
+    mod bytes {
+        use super::*;
+        add_bool_split_prefix!(NativeEndian);
+        add_split_prefix!(NativeEndian, u8, from_ne_bytes);
+        add_split_prefix!(NativeEndian, i8, from_ne_bytes);
+    }
+
+    #[cfg(feature = "integer_impls")]
+    mod integers {
+        use super::*;
+        add_split_prefix!(NativeEndian, u16, from_ne_bytes);
+        add_split_prefix!(NativeEndian, i16, from_ne_bytes);
+        add_split_prefix!(NativeEndian, u32, from_ne_bytes);
+        add_split_prefix!(NativeEndian, i32, from_ne_bytes);
+        add_split_prefix!(NativeEndian, u64, from_ne_bytes);
+        add_split_prefix!(NativeEndian, i64, from_ne_bytes);
+        add_split_prefix!(NativeEndian, u128, from_ne_bytes);
+        add_split_prefix!(NativeEndian, i128, from_ne_bytes);
+        add_split_prefix!(NativeEndian, usize, from_ne_bytes);
+        add_split_prefix!(NativeEndian, isize, from_ne_bytes);
+    }
+
+    #[cfg(feature = "float_impls")]
+    mod floats {
+        use super::*;
+        add_split_prefix!(NativeEndian, f32, from_ne_bytes);
+        add_split_prefix!(NativeEndian, f64, from_ne_bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::*;
+
+    #[cfg(all(
+        feature = "big_endian",
+        feature = "little_endian",
+        feature = "integer_impls",
+        feature = "float_impls"
+    ))]
+    #[test]
+    fn split_chained() {
+        let data = [
+            0x00, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc0, 0x3f, 0xaa,
+        ];
+        let (count, rest) = u16be::split_prefix(&data).unwrap();
+        let (id, rest) = u32le::split_prefix(rest).unwrap();
+        let (scale, rest) = f32le::split_prefix(rest).unwrap();
+        assert_eq!(count.to_len(), Some(2));
+        assert_eq!(id.to_native(), 1);
+        assert_eq!(scale.to_native(), 1.5);
+        assert_eq!(rest, [0xaa]);
+    }
+
+    #[cfg(all(feature = "native_endian", feature = "integer_impls"))]
+    #[test]
+    fn split_native() {
+        let (ne, _) = NativeEndian::<u16>::split_prefix(&0x1234u16.to_ne_bytes()).unwrap();
+        assert_eq!(ne.to_native(), 0x1234);
+    }
+
+    #[cfg(all(
+        feature = "big_endian",
+        feature = "little_endian",
+        feature = "integer_impls",
+        feature = "byte_impls"
+    ))]
+    #[test]
+    fn split_errors() {
+        assert!(u64be::split_prefix(&[1, 2, 3]).is_err());
+        assert!(BigEndian::<bool>::split_prefix(&[7]).is_err());
+        assert!(BigEndian::<bool>::split_prefix(&[]).is_err());
+        assert_eq!(
+            LittleEndian::<bool>::split_prefix(&[1, 9]),
+            Ok((true.into(), &[9u8][..]))
+        );
+    }
+}
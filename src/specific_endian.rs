@@ -1,4 +1,5 @@
 /// Any object implementing `SpecificEndian<T>` can be converted between big and little endian.  Implement this trait to allow for endian conversion by this crate.
+#[allow(clippy::wrong_self_convention)]
 pub trait SpecificEndian<T>
 where
     Self: Into<T> + Clone + Copy,
@@ -36,6 +37,24 @@ mod byte_impls {
     make_specific_endian_single_byte!(i8);
     // If bool ends up being represented by something other than a byte, this might not work right.
     make_specific_endian_single_byte!(bool);
+
+    /// Byte arrays have no byte order either, so `BigEndian<[u8; N]>` and `LittleEndian<[u8; N]>` store the bytes as
+    /// given.  This lets them be used in code that is generic over `SpecificEndian<T>`; otherwise `FixedBytes<N>` is
+    /// usually the better choice.
+    impl<const N: usize> SpecificEndian<[u8; N]> for [u8; N] {
+        fn to_big_endian(&self) -> Self {
+            *self
+        }
+        fn to_little_endian(&self) -> Self {
+            *self
+        }
+        fn from_big_endian(&self) -> Self {
+            *self
+        }
+        fn from_little_endian(&self) -> Self {
+            *self
+        }
+    }
 }
 
 #[cfg(feature = "integer_impls")]
@@ -100,6 +119,15 @@ mod float_impls {
     make_specific_endian_float!(f64);
 }
 
+/// `v` with its bytes reversed.  On a little-endian host, converting to big endian is the swap, and the other way round.
+fn swapped<T: SpecificEndian<T>>(v: &T) -> T {
+    if cfg!(target_endian = "little") {
+        v.to_big_endian()
+    } else {
+        v.to_little_endian()
+    }
+}
+
 /// A big-endian representation of type `T` that implements `SpecificEndian<T>`.  Data stored in the struct must be converted to big-endian using `::from()` or `.into()`.
 #[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
 #[repr(transparent)]
@@ -121,6 +149,24 @@ where
     pub fn to_native(&self) -> T {
         T::from_big_endian(&self.0)
     }
+    /// Stores `new`, and returns the old value in host-native endian.
+    pub fn replace(&mut self, new: T) -> T {
+        let old = self.to_native();
+        *self = Self::from(new);
+        old
+    }
+    /// Stores `T::default()`, and returns the old value in host-native endian.
+    pub fn take(&mut self) -> T
+    where
+        T: Default,
+    {
+        self.replace(T::default())
+    }
+    /// Reverses the order of the stored bytes, which corrects a value that was read in the wrong byte order.  Byte
+    /// arrays are left as they are, having no byte order.
+    pub fn swap_bytes_in_place(&mut self) {
+        self.0 = swapped(&self.0);
+    }
 }
 
 impl<T: SpecificEndian<T>> From<T> for BigEndian<T> {
@@ -150,6 +196,24 @@ where
     pub fn to_native(&self) -> T {
         T::from_little_endian(&self.0)
     }
+    /// Stores `new`, and returns the old value in host-native endian.
+    pub fn replace(&mut self, new: T) -> T {
+        let old = self.to_native();
+        *self = Self::from(new);
+        old
+    }
+    /// Stores `T::default()`, and returns the old value in host-native endian.
+    pub fn take(&mut self) -> T
+    where
+        T: Default,
+    {
+        self.replace(T::default())
+    }
+    /// Reverses the order of the stored bytes, which corrects a value that was read in the wrong byte order.  Byte
+    /// arrays are left as they are, having no byte order.
+    pub fn swap_bytes_in_place(&mut self) {
+        self.0 = swapped(&self.0);
+    }
 }
 
 impl<T: SpecificEndian<T>> From<T> for LittleEndian<T> {
@@ -158,6 +222,61 @@ impl<T: SpecificEndian<T>> From<T> for LittleEndian<T> {
     }
 }
 
+/// A host-native-endian representation of type `T` that implements `SpecificEndian<T>`.  Storing and loading never swap bytes.
+///
+/// **This is not portable.**  The stored bytes are in whatever order the host uses, so data written on a little-endian
+/// machine will be read back incorrectly on a big-endian one.  It is only meant for data that never leaves the machine,
+/// such as shared memory between processes on the same host, where the swap done by `BigEndian<T>` or `LittleEndian<T>`
+/// would be wasted work.
+#[cfg(feature = "native_endian")]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(transparent)]
+pub struct NativeEndian<T: SpecificEndian<T>>(pub(crate) T);
+
+#[cfg(feature = "native_endian")]
+impl<T> NativeEndian<T>
+where
+    T: SpecificEndian<T>,
+{
+    /// Returns the raw data stored in the struct.
+    pub fn to_bits(&self) -> T {
+        self.0
+    }
+    /// Imports the data raw into a `NativeEndian<T>` struct.
+    pub fn from_bits(v: T) -> Self {
+        Self(v)
+    }
+    /// Returns the stored value, which is already in host-native endian.
+    pub fn to_native(&self) -> T {
+        self.0
+    }
+    /// Stores `new`, and returns the old value in host-native endian.
+    pub fn replace(&mut self, new: T) -> T {
+        let old = self.to_native();
+        *self = Self::from(new);
+        old
+    }
+    /// Stores `T::default()`, and returns the old value in host-native endian.
+    pub fn take(&mut self) -> T
+    where
+        T: Default,
+    {
+        self.replace(T::default())
+    }
+    /// Reverses the order of the stored bytes, which corrects a value that was read in the wrong byte order.  Byte
+    /// arrays are left as they are, having no byte order.
+    pub fn swap_bytes_in_place(&mut self) {
+        self.0 = swapped(&self.0);
+    }
+}
+
+#[cfg(feature = "native_endian")]
+impl<T: SpecificEndian<T>> From<T> for NativeEndian<T> {
+    fn from(v: T) -> NativeEndian<T> {
+        NativeEndian::<T>(v)
+    }
+}
+
 #[cfg(feature = "big_endian")]
 mod big_endian_primatives {
     #[allow(unused_imports)]
@@ -174,11 +293,11 @@ mod big_endian_primatives {
         };
     }
 
-    #[cfg(feature = "integer_impls")]
+    #[cfg(feature = "byte_impls")]
     make_primitive_type_from_be!(bool);
-    #[cfg(feature = "integer_impls")]
+    #[cfg(feature = "byte_impls")]
     make_primitive_type_from_be!(u8);
-    #[cfg(feature = "integer_impls")]
+    #[cfg(feature = "byte_impls")]
     make_primitive_type_from_be!(i8);
     #[cfg(feature = "integer_impls")]
     make_primitive_type_from_be!(u16);
@@ -204,6 +323,12 @@ mod big_endian_primatives {
     make_primitive_type_from_be!(f32);
     #[cfg(feature = "float_impls")]
     make_primitive_type_from_be!(f64);
+    #[cfg(feature = "byte_impls")]
+    impl<const N: usize> From<BigEndian<[u8; N]>> for [u8; N] {
+        fn from(v: BigEndian<[u8; N]>) -> [u8; N] {
+            v.0.from_big_endian()
+        }
+    }
 }
 
 #[cfg(feature = "little_endian")]
@@ -222,11 +347,11 @@ mod little_endian_primatives {
         };
     }
 
-    #[cfg(feature = "integer_impls")]
+    #[cfg(feature = "byte_impls")]
     make_primitive_type_from_le!(bool);
-    #[cfg(feature = "integer_impls")]
+    #[cfg(feature = "byte_impls")]
     make_primitive_type_from_le!(u8);
-    #[cfg(feature = "integer_impls")]
+    #[cfg(feature = "byte_impls")]
     make_primitive_type_from_le!(i8);
     #[cfg(feature = "integer_impls")]
     make_primitive_type_from_le!(u16);
@@ -252,6 +377,66 @@ mod little_endian_primatives {
     make_primitive_type_from_le!(f32);
     #[cfg(feature = "float_impls")]
     make_primitive_type_from_le!(f64);
+    #[cfg(feature = "byte_impls")]
+    impl<const N: usize> From<LittleEndian<[u8; N]>> for [u8; N] {
+        fn from(v: LittleEndian<[u8; N]>) -> [u8; N] {
+            v.0.from_little_endian()
+        }
+    }
+}
+
+#[cfg(feature = "native_endian")]
+mod native_endian_primatives {
+    #[allow(unused_imports)]
+    use super::*;
+    // Rust's orphan trait rule prevents us from using a generic implementation on the primitive types, so we do this:
+    #[allow(unused_macros)]
+    macro_rules! make_primitive_type_from_ne {
+        ($wrap_ty:ty) => {
+            impl From<NativeEndian<$wrap_ty>> for $wrap_ty {
+                fn from(v: NativeEndian<$wrap_ty>) -> $wrap_ty {
+                    v.0
+                }
+            }
+        };
+    }
+
+    #[cfg(feature = "byte_impls")]
+    make_primitive_type_from_ne!(bool);
+    #[cfg(feature = "byte_impls")]
+    make_primitive_type_from_ne!(u8);
+    #[cfg(feature = "byte_impls")]
+    make_primitive_type_from_ne!(i8);
+    #[cfg(feature = "integer_impls")]
+    make_primitive_type_from_ne!(u16);
+    #[cfg(feature = "integer_impls")]
+    make_primitive_type_from_ne!(i16);
+    #[cfg(feature = "integer_impls")]
+    make_primitive_type_from_ne!(u32);
+    #[cfg(feature = "integer_impls")]
+    make_primitive_type_from_ne!(i32);
+    #[cfg(feature = "integer_impls")]
+    make_primitive_type_from_ne!(u64);
+    #[cfg(feature = "integer_impls")]
+    make_primitive_type_from_ne!(i64);
+    #[cfg(feature = "integer_impls")]
+    make_primitive_type_from_ne!(u128);
+    #[cfg(feature = "integer_impls")]
+    make_primitive_type_from_ne!(i128);
+    #[cfg(feature = "integer_impls")]
+    make_primitive_type_from_ne!(usize);
+    #[cfg(feature = "integer_impls")]
+    make_primitive_type_from_ne!(isize);
+    #[cfg(feature = "float_impls")]
+    make_primitive_type_from_ne!(f32);
+    #[cfg(feature = "float_impls")]
+    make_primitive_type_from_ne!(f64);
+    #[cfg(feature = "byte_impls")]
+    impl<const N: usize> From<NativeEndian<[u8; N]>> for [u8; N] {
+        fn from(v: NativeEndian<[u8; N]>) -> [u8; N] {
+            v.0
+        }
+    }
 }
 
 #[cfg(feature = "both_endian")]
@@ -353,7 +538,7 @@ mod tests {
     #[test]
     fn store_be() {
         let be: BigEndian<u64> = 0xfe.into();
-        if cfg!(byte_order = "big endian") {
+        if cfg!(target_endian = "big") {
             assert_eq!(be.to_bits(), 0xfe);
         } else {
             assert_eq!(be.to_bits(), 0xfe00000000000000);
@@ -368,7 +553,7 @@ mod tests {
     #[test]
     fn store_le() {
         let le: LittleEndian<u64> = 0xfe.into();
-        if cfg!(byte_order = "big endian") {
+        if cfg!(target_endian = "big") {
             assert_eq!(le.to_bits(), 0xfe00000000000000);
         } else {
             assert_eq!(le.to_bits(), 0xfe);
@@ -398,7 +583,7 @@ mod tests {
     #[test]
     fn store_fp_be() {
         let be1 = BigEndian::<f64>::from(1234.5678);
-        if cfg!(byte_order = "little endian") {
+        if cfg!(target_endian = "little") {
             assert_ne!(1234.5678, be1.to_bits());
         }
         assert_eq!(1234.5678, f64::from(be1));
@@ -407,7 +592,7 @@ mod tests {
     #[test]
     fn store_fp_le() {
         let le1 = LittleEndian::<f64>::from(1234.5678);
-        if cfg!(byte_order = "big endian") {
+        if cfg!(target_endian = "big") {
             assert_ne!(1234.5678, le1.to_bits());
         }
         assert_eq!(1234.5678, f64::from(le1));
@@ -453,6 +638,58 @@ mod tests {
         assert_eq!(me, 300.into());
     }
 
+    #[test]
+    fn byte_array_unchanged() {
+        let mac = [0x00, 0x1b, 0x21, 0x3a, 0x4c, 0x5d];
+        let be = BigEndian::from(mac);
+        let le = LittleEndian::from(mac);
+        assert_eq!(be.to_bits(), mac);
+        assert_eq!(le.to_bits(), mac);
+        assert_eq!(<[u8; 6]>::from(be), mac);
+        assert_eq!(le.to_native(), mac);
+        assert_eq!(size_of::<BigEndian<[u8; 6]>>(), 6);
+    }
+
+    #[test]
+    fn replace_and_take() {
+        let mut be = BigEndian::<u16>::from(0x1234);
+        assert_eq!(be.replace(0xabcd), 0x1234);
+        assert_eq!(be.to_bits(), 0xabcdu16.to_be());
+        let mut le = LittleEndian::<f32>::from(2.5);
+        assert_eq!(le.take(), 2.5);
+        assert_eq!(le.to_native(), 0.0);
+        assert_eq!(le.replace(-1.0), 0.0);
+        assert_eq!(le.to_native(), -1.0);
+    }
+
+    #[test]
+    fn swap_bytes_in_place() {
+        // Fields of a header that claimed to be big endian but was written little endian.
+        let mut magic = BigEndian::<u32>::from_bits(0xcafef00du32.to_le());
+        let mut scale = BigEndian::<f64>::from_bits(f64::from_bits(1.5f64.to_bits().to_le()));
+        let mut label = BigEndian::<[u8; 4]>::from(*b"HEAD");
+        magic.swap_bytes_in_place();
+        scale.swap_bytes_in_place();
+        label.swap_bytes_in_place();
+        assert_eq!(magic.to_native(), 0xcafef00d);
+        assert_eq!(scale.to_native(), 1.5);
+        assert_eq!(label.to_native(), *b"HEAD");
+
+        let mut le = LittleEndian::<i16>::from(-2);
+        le.swap_bytes_in_place();
+        assert_eq!(le.to_native(), (-2i16).swap_bytes());
+    }
+
+    #[cfg(feature = "native_endian")]
+    #[test]
+    fn native_never_swaps() {
+        let ne = NativeEndian::<u32>::from(0x12345678);
+        assert_eq!(ne.to_bits(), 0x12345678);
+        assert_eq!(ne.to_native(), 0x12345678);
+        assert_eq!(u32::from(ne), 0x12345678);
+        assert_eq!(ne.to_bits().to_ne_bytes(), 0x12345678u32.to_ne_bytes());
+    }
+
     #[test]
     fn custom_type() {
         #[derive(Copy, Clone, Debug)]
@@ -471,7 +708,7 @@ mod tests {
             }
             fn to_little_endian(&self) -> Self {
                 match self {
-                    EndianAwareExample::LittleEndianFunction(_0) => *self,
+                    EndianAwareExample::LittleEndianFunction(_) => *self,
                     EndianAwareExample::BigEndianFunction(v) => {
                         EndianAwareExample::BigEndianFunction(v.to_little_endian())
                     }
@@ -479,7 +716,7 @@ mod tests {
             }
             fn from_big_endian(&self) -> Self {
                 match self {
-                    EndianAwareExample::BigEndianFunction(_0) => *self,
+                    EndianAwareExample::BigEndianFunction(_) => *self,
                     EndianAwareExample::LittleEndianFunction(v) => {
                         EndianAwareExample::BigEndianFunction(v.to_big_endian())
                     }
@@ -511,3 +748,181 @@ mod tests {
         assert_eq!(value, 0x0f000000000000000);
     }
 }
+
+#[cfg(test)]
+mod proptests {
+    use crate::*;
+    use proptest::prelude::*;
+
+    /// Round-trips through both wrappers, and checks that the stored bytes are the expected byte order regardless of the host.
+    macro_rules! roundtrip_integer {
+        ($name:ident, $ty:ty) => {
+            proptest! {
+                #[test]
+                fn $name(x: $ty) {
+                    let be = BigEndian::<$ty>::from(x);
+                    let le = LittleEndian::<$ty>::from(x);
+                    prop_assert_eq!(be.to_native(), x);
+                    prop_assert_eq!(le.to_native(), x);
+                    prop_assert_eq!(<$ty>::from(be), x);
+                    prop_assert_eq!(<$ty>::from(le), x);
+                    prop_assert_eq!(BigEndian::from_bits(be.to_bits()), be);
+                    prop_assert_eq!(LittleEndian::from_bits(le.to_bits()), le);
+                    prop_assert_eq!(be.to_bits().to_ne_bytes(), x.to_be_bytes());
+                    prop_assert_eq!(le.to_bits().to_ne_bytes(), x.to_le_bytes());
+                    prop_assert_eq!(BigEndian::from(le), be);
+                    prop_assert_eq!(LittleEndian::from(be), le);
+                }
+            }
+        };
+    }
+
+    roundtrip_integer!(roundtrip_u8, u8);
+    roundtrip_integer!(roundtrip_i8, i8);
+    roundtrip_integer!(roundtrip_u16, u16);
+    roundtrip_integer!(roundtrip_i16, i16);
+    roundtrip_integer!(roundtrip_u32, u32);
+    roundtrip_integer!(roundtrip_i32, i32);
+    roundtrip_integer!(roundtrip_u64, u64);
+    roundtrip_integer!(roundtrip_i64, i64);
+    roundtrip_integer!(roundtrip_u128, u128);
+    roundtrip_integer!(roundtrip_i128, i128);
+    roundtrip_integer!(roundtrip_usize, usize);
+    roundtrip_integer!(roundtrip_isize, isize);
+
+    /// Floats are generated from arbitrary bit patterns and compared by bits, so that NaNs and subnormals round-trip too.
+    macro_rules! roundtrip_float {
+        ($name:ident, $ty:ty, $bits_ty:ty) => {
+            proptest! {
+                #[test]
+                fn $name(bits: $bits_ty) {
+                    let x = <$ty>::from_bits(bits);
+                    let be = BigEndian::<$ty>::from(x);
+                    let le = LittleEndian::<$ty>::from(x);
+                    prop_assert_eq!(be.to_native().to_bits(), x.to_bits());
+                    prop_assert_eq!(le.to_native().to_bits(), x.to_bits());
+                    prop_assert_eq!(<$ty>::from(be).to_bits(), x.to_bits());
+                    prop_assert_eq!(<$ty>::from(le).to_bits(), x.to_bits());
+                    prop_assert_eq!(be.to_bits().to_bits().to_ne_bytes(), x.to_be_bytes());
+                    prop_assert_eq!(le.to_bits().to_bits().to_ne_bytes(), x.to_le_bytes());
+                    prop_assert_eq!(BigEndian::from(le).to_native().to_bits(), x.to_bits());
+                    prop_assert_eq!(LittleEndian::from(be).to_native().to_bits(), x.to_bits());
+                }
+            }
+        };
+    }
+
+    roundtrip_float!(roundtrip_f32, f32, u32);
+    roundtrip_float!(roundtrip_f64, f64, u64);
+
+    proptest! {
+        #[test]
+        fn roundtrip_bool(x: bool) {
+            prop_assert_eq!(BigEndian::from(x).to_native(), x);
+            prop_assert_eq!(LittleEndian::from(x).to_native(), x);
+            prop_assert_eq!(bool::from(BigEndian::from(x)), x);
+            prop_assert_eq!(bool::from(LittleEndian::from(x)), x);
+        }
+    }
+
+    /// Round-trips every wrapper through `write_specific`/`read_specific`, checking the bytes on the wire against
+    /// `to_be_bytes`/`to_le_bytes`/`to_ne_bytes`, and that `split_prefix` parses the same bytes back.
+    #[cfg(feature = "io-std")]
+    mod wire {
+        use crate::*;
+        use proptest::prelude::*;
+
+        macro_rules! wire_roundtrip {
+            ($wrapper:ident, $ty:ty, $x:expr, $bytes:expr) => {{
+                let value = $wrapper::<$ty>::from($x);
+                let mut buf = Vec::new();
+                write_specific(&mut buf, &value).unwrap();
+                prop_assert_eq!(&buf[..], &$bytes[..]);
+                prop_assert_eq!(buf.len(), <$wrapper<$ty>>::WIRE_SIZE);
+                let read: $wrapper<$ty> = read_specific(&mut &buf[..]).unwrap();
+                let (split, rest) = $wrapper::<$ty>::split_prefix(&buf).unwrap();
+                prop_assert!(rest.is_empty());
+                (read, split)
+            }};
+        }
+
+        macro_rules! wire_integer {
+            ($name:ident, $ty:ty) => {
+                proptest! {
+                    #[test]
+                    fn $name(x: $ty) {
+                        let (read, split) = wire_roundtrip!(BigEndian, $ty, x, x.to_be_bytes());
+                        prop_assert_eq!(read.to_native(), x);
+                        prop_assert_eq!(split.to_native(), x);
+                        let (read, split) = wire_roundtrip!(LittleEndian, $ty, x, x.to_le_bytes());
+                        prop_assert_eq!(read.to_native(), x);
+                        prop_assert_eq!(split.to_native(), x);
+                        #[cfg(feature = "native_endian")]
+                        {
+                            let (read, split) = wire_roundtrip!(NativeEndian, $ty, x, x.to_ne_bytes());
+                            prop_assert_eq!(read.to_native(), x);
+                            prop_assert_eq!(split.to_native(), x);
+                        }
+                    }
+                }
+            };
+        }
+
+        wire_integer!(wire_u8, u8);
+        wire_integer!(wire_i8, i8);
+        wire_integer!(wire_u16, u16);
+        wire_integer!(wire_i16, i16);
+        wire_integer!(wire_u32, u32);
+        wire_integer!(wire_i32, i32);
+        wire_integer!(wire_u64, u64);
+        wire_integer!(wire_i64, i64);
+        wire_integer!(wire_u128, u128);
+        wire_integer!(wire_i128, i128);
+        wire_integer!(wire_usize, usize);
+        wire_integer!(wire_isize, isize);
+
+        macro_rules! wire_float {
+            ($name:ident, $ty:ty, $bits_ty:ty) => {
+                proptest! {
+                    #[test]
+                    fn $name(bits: $bits_ty) {
+                        let x = <$ty>::from_bits(bits);
+                        let (read, split) = wire_roundtrip!(BigEndian, $ty, x, x.to_be_bytes());
+                        prop_assert_eq!(read.to_native().to_bits(), bits);
+                        prop_assert_eq!(split.to_native().to_bits(), bits);
+                        let (read, split) = wire_roundtrip!(LittleEndian, $ty, x, x.to_le_bytes());
+                        prop_assert_eq!(read.to_native().to_bits(), bits);
+                        prop_assert_eq!(split.to_native().to_bits(), bits);
+                        #[cfg(feature = "native_endian")]
+                        {
+                            let (read, split) = wire_roundtrip!(NativeEndian, $ty, x, x.to_ne_bytes());
+                            prop_assert_eq!(read.to_native().to_bits(), bits);
+                            prop_assert_eq!(split.to_native().to_bits(), bits);
+                        }
+                    }
+                }
+            };
+        }
+
+        wire_float!(wire_f32, f32, u32);
+        wire_float!(wire_f64, f64, u64);
+
+        proptest! {
+            #[test]
+            fn wire_bool(x: bool) {
+                let (read, split) = wire_roundtrip!(BigEndian, bool, x, [x as u8]);
+                prop_assert_eq!(read.to_native(), x);
+                prop_assert_eq!(split.to_native(), x);
+                let (read, split) = wire_roundtrip!(LittleEndian, bool, x, [x as u8]);
+                prop_assert_eq!(read.to_native(), x);
+                prop_assert_eq!(split.to_native(), x);
+                #[cfg(feature = "native_endian")]
+                {
+                    let (read, split) = wire_roundtrip!(NativeEndian, bool, x, [x as u8]);
+                    prop_assert_eq!(read.to_native(), x);
+                    prop_assert_eq!(split.to_native(), x);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+/*!
+A seconds + fraction timestamp, as used by pcap record headers and many other log formats.  The two fields are stored in
+whichever endian wrapper is chosen for `E`, e.g. `WireTimestamp<u32be>`.
+
+```rust
+use simple_endian::*;
+use std::time::Duration;
+
+let ts = WireTimestamp::<u32le>::from_duration(Duration::from_micros(1_500_000), TimestampResolution::Micros).unwrap();
+assert_eq!(ts.secs.to_native(), 1);
+assert_eq!(ts.subsec.to_native(), 500_000);
+```
+*/
+use std::io::{self, Read, Write};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::*;
+
+/// The unit of the sub-second field of a `WireTimestamp`.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum TimestampResolution {
+    Micros,
+    Nanos,
+}
+
+impl TimestampResolution {
+    /// The number of sub-second units in one second.
+    pub fn units_per_sec(&self) -> u32 {
+        match self {
+            TimestampResolution::Micros => 1_000_000,
+            TimestampResolution::Nanos => 1_000_000_000,
+        }
+    }
+}
+
+/// A 32-bit seconds count and a 32-bit sub-second count, each stored as an `E` (`u32be` or `u32le`).  The resolution of
+/// `subsec` isn't stored, so it has to be given when converting.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct WireTimestamp<E> {
+    pub secs: E,
+    pub subsec: E,
+}
+
+impl<E> WireTimestamp<E>
+where
+    E: From<u32> + Into<u32> + Copy,
+{
+    /// Builds a timestamp from a duration.  Anything finer than `resolution` is truncated.  Fails if the seconds don't fit in 32 bits.
+    pub fn from_duration(
+        d: Duration,
+        resolution: TimestampResolution,
+    ) -> Result<Self, &'static str> {
+        let secs = u32::try_from(d.as_secs()).map_err(|_| "timestamp seconds overflow u32")?;
+        let subsec = match resolution {
+            TimestampResolution::Micros => d.subsec_micros(),
+            TimestampResolution::Nanos => d.subsec_nanos(),
+        };
+        Ok(Self {
+            secs: secs.into(),
+            subsec: subsec.into(),
+        })
+    }
+
+    /// Converts the timestamp to a duration.  Fails if `subsec` is not less than one second at `resolution`.
+    pub fn to_duration(&self, resolution: TimestampResolution) -> Result<Duration, &'static str> {
+        let subsec: u32 = self.subsec.into();
+        if subsec >= resolution.units_per_sec() {
+            return Err("timestamp sub-second field out of range");
+        }
+        let nanos = subsec * (1_000_000_000 / resolution.units_per_sec());
+        Ok(Duration::new(self.secs.into() as u64, nanos))
+    }
+
+    /// Builds a timestamp from a time since the Unix epoch.  Fails for times before the epoch or after 2106.
+    pub fn from_system_time(
+        t: SystemTime,
+        resolution: TimestampResolution,
+    ) -> Result<Self, &'static str> {
+        let d = t
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| "timestamp is before the Unix epoch")?;
+        Self::from_duration(d, resolution)
+    }
+
+    /// Converts the timestamp, taken as a time since the Unix epoch, into a `SystemTime`.
+    pub fn to_system_time(
+        &self,
+        resolution: TimestampResolution,
+    ) -> Result<SystemTime, &'static str> {
+        Ok(UNIX_EPOCH + self.to_duration(resolution)?)
+    }
+}
+
+impl<E: EndianRead> EndianRead for WireTimestamp<E> {
+    fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+        let secs = E::read_from(reader)?;
+        let subsec = E::read_from(reader)?;
+        Ok(Self { secs, subsec })
+    }
+}
+
+impl<E: EndianWrite> EndianWrite for WireTimestamp<E> {
+    fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        self.secs.write_to(writer)?;
+        self.subsec.write_to(writer)
+    }
+}
+
+impl<E: WireSize> WireSize for WireTimestamp<E> {
+    const WIRE_SIZE: usize = 2 * E::WIRE_SIZE;
+}
+
+/// Reads a `SystemTime` stored as a seconds count `S` (e.g. `u64be`) and a nanoseconds count `N` (e.g. `u32be`) since
+/// the Unix epoch.  Nanoseconds of a second or more, or a time too far in the future for `SystemTime`, are an
+/// `InvalidData` error.
+pub fn read_system_time<R, S, N>(reader: &mut R) -> io::Result<SystemTime>
+where
+    R: Read + ?Sized,
+    S: EndianRead + Into<u64>,
+    N: EndianRead + Into<u32>,
+{
+    let secs: u64 = S::read_from(reader)?.into();
+    let nanos: u32 = N::read_from(reader)?.into();
+    if nanos >= 1_000_000_000 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "timestamp nanoseconds out of range",
+        ));
+    }
+    UNIX_EPOCH
+        .checked_add(Duration::new(secs, nanos))
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "timestamp out of range"))
+}
+
+/// Writes `t` as a seconds count `S` and a nanoseconds count `N` since the Unix epoch.  Times before the epoch are an
+/// `InvalidInput` error.
+pub fn write_system_time<W, S, N>(writer: &mut W, t: SystemTime) -> io::Result<()>
+where
+    W: Write + ?Sized,
+    S: EndianWrite + From<u64>,
+    N: EndianWrite + From<u32>,
+{
+    let d = t.duration_since(UNIX_EPOCH).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "timestamp is before the Unix epoch",
+        )
+    })?;
+    S::from(d.as_secs()).write_to(writer)?;
+    N::from(d.subsec_nanos()).write_to(writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn roundtrip_epoch_time() {
+        let t = UNIX_EPOCH + Duration::new(1_600_000_000, 123_456_000);
+        let ts = WireTimestamp::<u32be>::from_system_time(t, TimestampResolution::Micros).unwrap();
+        assert_eq!(ts.subsec.to_native(), 123_456);
+
+        let mut buf = Vec::new();
+        write_specific(&mut buf, &ts).unwrap();
+        assert_eq!(buf, [0x5f, 0x5e, 0x10, 0x00, 0x00, 0x01, 0xe2, 0x40]);
+
+        let read: WireTimestamp<u32be> = read_specific(&mut &buf[..]).unwrap();
+        assert_eq!(read, ts);
+        assert_eq!(read.to_system_time(TimestampResolution::Micros).unwrap(), t);
+    }
+
+    #[test]
+    fn nanosecond_resolution_le() {
+        let d = Duration::new(7, 999_999_999);
+        let ts = WireTimestamp::<u32le>::from_duration(d, TimestampResolution::Nanos).unwrap();
+        assert_eq!(ts.to_duration(TimestampResolution::Nanos).unwrap(), d);
+        // The same fraction at microsecond resolution loses the last three digits.
+        let ts = WireTimestamp::<u32le>::from_duration(d, TimestampResolution::Micros).unwrap();
+        assert_eq!(
+            ts.to_duration(TimestampResolution::Micros).unwrap(),
+            Duration::new(7, 999_999_000)
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        let ts = WireTimestamp::<u32be> {
+            secs: 0.into(),
+            subsec: 1_000_000.into(),
+        };
+        assert!(ts.to_duration(TimestampResolution::Micros).is_err());
+        assert!(ts.to_duration(TimestampResolution::Nanos).is_ok());
+        assert!(WireTimestamp::<u32be>::from_duration(
+            Duration::from_secs(1 << 32),
+            TimestampResolution::Nanos
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn roundtrip_system_time() {
+        let now = std::time::SystemTime::now();
+        let mut buf = Vec::new();
+        write_system_time::<_, u64be, u32be>(&mut buf, now).unwrap();
+        assert_eq!(buf.len(), 12);
+        let read = read_system_time::<_, u64be, u32be>(&mut &buf[..]).unwrap();
+        // Nanoseconds are the finest resolution SystemTime has, so nothing is lost.
+        assert_eq!(read, now);
+
+        let mut buf = Vec::new();
+        write_system_time::<_, u64le, u32le>(&mut buf, UNIX_EPOCH + Duration::new(1, 5)).unwrap();
+        assert_eq!(buf, [1, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0]);
+    }
+
+    #[test]
+    fn system_time_out_of_range() {
+        let before_epoch = UNIX_EPOCH - Duration::from_secs(1);
+        let err = write_system_time::<_, u64be, u32be>(&mut Vec::new(), before_epoch).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+
+        let bad_nanos = [0, 0, 0, 0, 0, 0, 0, 0, 0x3b, 0x9a, 0xca, 0x00];
+        let err = read_system_time::<_, u64be, u32be>(&mut &bad_nanos[..]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
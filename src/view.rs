@@ -0,0 +1,82 @@
+/*!
+Reading values out of a byte buffer at a given offset, such as a memory-mapped file from any mmap crate.  The bytes are
+copied out and decoded through `EndianRead`, so no pointer into the buffer is ever dereferenced, and the offset doesn't
+need to be aligned.
+
+```rust
+use simple_endian::*;
+
+let bytes = [0xffu8, 0x00, 0x00, 0x01, 0x00, 0x02];
+let first: u16be = view(&bytes, 1).unwrap();
+let both: [u16be; 2] = view_array(&bytes, 2).unwrap();
+assert_eq!(first.to_native(), 0);
+assert_eq!(both, [1.into(), 2.into()]);
+```
+*/
+use std::vec::Vec;
+
+use super::*;
+
+/// Reads a `T` from `bytes`, starting at `offset`.  Fails if the value runs past the end of `bytes`, or if the bytes in
+/// range aren't a valid `T`.
+pub fn view<T: EndianRead + WireSize>(bytes: &[u8], offset: usize) -> Result<T, &'static str> {
+    let mut window = window(bytes, offset, T::WIRE_SIZE)?;
+    T::read_from(&mut window).map_err(|_| "view contains an invalid value")
+}
+
+/// Reads `N` consecutive `T`s from `bytes`, starting at `offset`.
+pub fn view_array<T: EndianRead + WireSize, const N: usize>(
+    bytes: &[u8],
+    offset: usize,
+) -> Result<[T; N], &'static str> {
+    let len = T::WIRE_SIZE.checked_mul(N).ok_or("view is out of bounds")?;
+    let mut window = window(bytes, offset, len)?;
+    let mut values = Vec::with_capacity(N);
+    for _ in 0..N {
+        values.push(T::read_from(&mut window).map_err(|_| "view contains an invalid value")?);
+    }
+    values
+        .try_into()
+        .map_err(|_| "view contains an invalid value")
+}
+
+/// The `len` bytes of `bytes` starting at `offset`.
+fn window(bytes: &[u8], offset: usize, len: usize) -> Result<&[u8], &'static str> {
+    offset
+        .checked_add(len)
+        .and_then(|end| bytes.get(offset..end))
+        .ok_or("view is out of bounds")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn view_unaligned() {
+        // A header with a u32be at offset 1 and a table of u64le at offset 5, neither of them aligned.
+        let mut buf = vec![0u8];
+        buf.extend_from_slice(&0xcafef00du32.to_be_bytes());
+        for v in [1u64, 2, 3].iter() {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        let magic: u32be = view(&buf, 1).unwrap();
+        assert_eq!(magic.to_native(), 0xcafef00d);
+        let table: [u64le; 3] = view_array(&buf, 5).unwrap();
+        assert_eq!(table, [1.into(), 2.into(), 3.into()]);
+    }
+
+    #[test]
+    fn view_out_of_bounds() {
+        let buf = [0u8; 8];
+        assert!(view::<u64be>(&buf, 0).is_ok());
+        assert!(view::<u64be>(&buf, 1).is_err());
+        assert!(view::<u16be>(&buf, usize::MAX).is_err());
+        assert!(view_array::<u32le, 3>(&buf, 0).is_err());
+    }
+
+    #[test]
+    fn view_invalid() {
+        assert!(view::<BigEndian<bool>>(&[2u8], 0).is_err());
+    }
+}
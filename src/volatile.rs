@@ -0,0 +1,128 @@
+/*!
+`VolatileEndian<E>`, for specific-endian values in memory that something other than the program can change or observe,
+such as a memory-mapped hardware register or a shared-memory page.  Every `read()` and `write()` is a volatile access
+of the stored bytes, so the compiler can't merge, reorder, or remove them.
+
+```rust
+use simple_endian::*;
+
+let status = VolatileEndian::new(u32be::from(0));
+status.write(0x8000_0001);
+assert_eq!(status.read(), 0x8000_0001);
+assert_eq!(status.into_inner().to_bits(), 0x8000_0001u32.to_be());
+```
+
+On mapped memory, `VolatileEndian::from_ptr` turns a pointer into the mapping into a reference, under the safety
+conditions it lists.
+*/
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::ptr;
+
+use super::*;
+
+/// A specific-endian value `E` that is only ever loaded and stored with volatile accesses.  Stores go through a shared
+/// reference, since the memory is not the program's alone.
+#[derive(Default)]
+#[repr(transparent)]
+pub struct VolatileEndian<E>(UnsafeCell<E>);
+
+impl<E: Copy> VolatileEndian<E> {
+    /// Wraps `value`.
+    pub fn new(value: E) -> Self {
+        Self(UnsafeCell::new(value))
+    }
+
+    /// Treats the `E` at `ptr` as a `VolatileEndian<E>`, such as a register in a memory-mapped device.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be non-null, aligned for `E`, and valid for volatile reads and writes of `E` for all of `'a`.  For
+    /// that lifetime the memory must not also be accessed through a reference that isn't volatile.
+    pub unsafe fn from_ptr<'a>(ptr: *mut E) -> &'a Self {
+        // SAFETY: `VolatileEndian<E>` is transparent over `UnsafeCell<E>`, which has the layout of `E`, and the caller
+        // guarantees the pointer is valid for `'a`.
+        unsafe { &*(ptr as *const Self) }
+    }
+
+    /// Loads the stored value, still in its specific endian.
+    pub fn read_raw(&self) -> E {
+        // SAFETY: the pointer comes from the cell, so it is valid and aligned for a read of `E`.
+        unsafe { ptr::read_volatile(self.0.get()) }
+    }
+
+    /// Stores `value`, already in its specific endian.
+    pub fn write_raw(&self, value: E) {
+        // SAFETY: the pointer comes from the cell, so it is valid and aligned for a write of `E`, and `UnsafeCell`
+        // allows writing through a shared reference.  The type isn't `Sync`, so no other thread holds `self`.
+        unsafe { ptr::write_volatile(self.0.get(), value) }
+    }
+
+    /// Unwraps the stored value.
+    pub fn into_inner(self) -> E {
+        self.0.into_inner()
+    }
+}
+
+/// Formats the value from a single volatile read.
+impl<E: Copy + fmt::Debug> fmt::Debug for VolatileEndian<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("VolatileEndian")
+            .field(&self.read_raw())
+            .finish()
+    }
+}
+
+macro_rules! add_volatile_ops {
+    ($wrapper:ident) => {
+        impl<T: SpecificEndian<T>> VolatileEndian<$wrapper<T>> {
+            /// Loads the stored value and converts it to host-native endian.
+            pub fn read(&self) -> T {
+                self.read_raw().to_native()
+            }
+
+            /// Converts `value` from host-native endian and stores it.
+            pub fn write(&self, value: T) {
+                self.write_raw($wrapper::from(value))
+            }
+        }
+    };
+}
+
+add_volatile_ops!(BigEndian);
+add_volatile_ops!(LittleEndian);
+#[cfg(feature = "native_endian")]
+add_volatile_ops!(NativeEndian);
+
+#[cfg(all(test, feature = "integer_impls"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn read_write_stack_value() {
+        let reg = VolatileEndian::new(LittleEndian::<u16>::from(0x1234));
+        assert_eq!(reg.read(), 0x1234);
+        reg.write(0xbeef);
+        assert_eq!(reg.read(), 0xbeef);
+        assert_eq!(reg.read_raw().to_bits(), 0xbeefu16.to_le());
+        assert_eq!(
+            std::format!("{:?}", reg),
+            std::format!("VolatileEndian({:?})", reg.read_raw())
+        );
+
+        reg.write_raw(LittleEndian::from_bits(0x0102u16.to_le()));
+        assert_eq!(reg.into_inner().to_native(), 0x0102);
+    }
+
+    #[test]
+    fn from_pointer() {
+        // A four-byte register block, accessed through a pointer as mapped memory would be.
+        let mut block = BigEndian::<u32>::from(0);
+        let ptr: *mut BigEndian<u32> = &mut block;
+        // SAFETY: `block` outlives `reg` and is only accessed through it until `reg` is last used.
+        let reg = unsafe { VolatileEndian::from_ptr(ptr) };
+        reg.write(0xdead_beef);
+        assert_eq!(reg.read(), 0xdead_beef);
+        assert_eq!(block.to_native(), 0xdead_beef);
+    }
+}
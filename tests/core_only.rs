@@ -0,0 +1,84 @@
+//! Uses only the core API, in a `no_std` crate, so that it keeps building with `--no-default-features`.  Run it with
+//! `cargo test --no-default-features --test core_only` to catch modules that depend on features they don't enable.
+#![no_std]
+
+use simple_endian::{BigEndian, LittleEndian, SpecificEndian};
+
+/// A type that gets its `SpecificEndian` impl from here rather than from any of the crate's features.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+struct Word(u16);
+
+impl SpecificEndian<Word> for Word {
+    fn to_big_endian(&self) -> Self {
+        Word(self.0.to_be())
+    }
+    fn to_little_endian(&self) -> Self {
+        Word(self.0.to_le())
+    }
+    fn from_big_endian(&self) -> Self {
+        Word(u16::from_be(self.0))
+    }
+    fn from_little_endian(&self) -> Self {
+        Word(u16::from_le(self.0))
+    }
+}
+
+#[test]
+fn custom_type_roundtrip() {
+    let be = BigEndian::from(Word(0x1234));
+    let le = LittleEndian::from(Word(0x1234));
+    assert_eq!(be.to_native(), Word(0x1234));
+    assert_eq!(le.to_native(), Word(0x1234));
+    assert_eq!(be.to_bits(), Word(0x1234u16.to_be()));
+    assert_eq!(le.to_bits(), Word(0x1234u16.to_le()));
+    assert_eq!(BigEndian::from_bits(be.to_bits()), be);
+}
+
+#[cfg(feature = "integer_impls")]
+#[test]
+fn primitive_roundtrip() {
+    let be = BigEndian::<u32>::from(0xdeadbeef);
+    let le = LittleEndian::<i64>::from(-2);
+    assert_eq!(be.to_native(), 0xdeadbeef);
+    assert_eq!(le.to_native(), -2);
+    assert_eq!(be.to_bits(), 0xdeadbeefu32.to_be());
+}
+
+/// Formats into a fixed buffer on the stack, since there's no `String` without `alloc`.
+#[cfg(all(feature = "format", feature = "integer_impls"))]
+struct StackBuf {
+    buf: [u8; 32],
+    len: usize,
+}
+
+#[cfg(all(feature = "format", feature = "integer_impls"))]
+impl core::fmt::Write for StackBuf {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(core::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[cfg(all(feature = "format", feature = "integer_impls"))]
+#[test]
+fn format_without_alloc() {
+    use core::fmt::Write;
+    let mut out = StackBuf {
+        buf: [0; 32],
+        len: 0,
+    };
+    write!(
+        out,
+        "{} {:x} {}",
+        BigEndian::<u32>::from(42),
+        LittleEndian::<u16>::from(0xbeef),
+        LittleEndian::<i64>::from(-7)
+    )
+    .unwrap();
+    assert_eq!(&out.buf[..out.len], b"42 beef -7");
+}